@@ -0,0 +1,171 @@
+/*!
+# QR Code Generator Macros
+
+Procedural macros of the [qrcode-generator](https://crates.io/crates/qrcode-generator) crate. Use them via the `macros` feature of that crate.
+*/
+
+use proc_macro::{Delimiter, Group, Literal, TokenStream, TokenTree};
+use qrcodegen::{QrCode, QrCodeEcc};
+
+/// Encode a string literal to a QR code matrix at compile time. The result is a `[[bool; N]; N]` array of rows, the same as the `to_matrix_from_str` function returns.
+///
+/// The error correction level is `Low`, `Medium`, `Quartile` or `High`, optionally prefixed with a path such as `QrCodeEcc::`.
+///
+/// ```ignore
+/// const MATRIX: [[bool; 21]; 21] = qr_static!("Hello world!", QrCodeEcc::Low);
+/// ```
+#[proc_macro]
+pub fn qr_static(input: TokenStream) -> TokenStream {
+    match qr_static_inner(input) {
+        Ok(output) => output,
+        Err(message) => compile_error(&message),
+    }
+}
+
+fn qr_static_inner(input: TokenStream) -> Result<TokenStream, String> {
+    let mut tokens = input.into_iter().peekable();
+
+    let text = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => parse_str_literal(&literal)?,
+        // a literal passed through `macro_rules!` is wrapped in an invisible group
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::None => {
+            match group.stream().into_iter().next() {
+                Some(TokenTree::Literal(literal)) => parse_str_literal(&literal)?,
+                _ => return Err("expected a string literal".to_string()),
+            }
+        },
+        _ => return Err("expected a string literal".to_string()),
+    };
+
+    match tokens.next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => (),
+        _ => return Err("expected `,` after the string literal".to_string()),
+    }
+
+    let mut ecc = None;
+
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => ecc = Some(ident.to_string()),
+            TokenTree::Punct(punct) if punct.as_char() == ':' => (),
+            TokenTree::Punct(punct) if punct.as_char() == ',' => break,
+            _ => return Err("expected an error correction level".to_string()),
+        }
+    }
+
+    let ecc = match ecc.as_deref() {
+        Some("Low") => QrCodeEcc::Low,
+        Some("Medium") => QrCodeEcc::Medium,
+        Some("Quartile") => QrCodeEcc::Quartile,
+        Some("High") => QrCodeEcc::High,
+        _ => {
+            return Err("expected an error correction level: `Low`, `Medium`, `Quartile` or `High`"
+                .to_string())
+        },
+    };
+
+    let qr = QrCode::encode_text(&text, ecc)
+        .map_err(|_| "the supplied data does not fit any QR Code version".to_string())?;
+
+    let size = qr.size();
+
+    let mut rows = String::with_capacity((size * size * 6) as usize);
+
+    rows.push('[');
+
+    for y in 0..size {
+        rows.push('[');
+
+        for x in 0..size {
+            rows.push_str(if qr.get_module(x, y) { "true," } else { "false," });
+        }
+
+        rows.push_str("],");
+    }
+
+    rows.push(']');
+
+    Ok(rows.parse().unwrap())
+}
+
+/// Get the value of a string literal, which may be a raw string.
+fn parse_str_literal(literal: &Literal) -> Result<String, String> {
+    let s = literal.to_string();
+
+    if let Some(raw) = s.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+
+        return raw
+            .get((hashes + 1)..(raw.len() - hashes - 1))
+            .map(String::from)
+            .ok_or_else(|| "expected a string literal".to_string());
+    }
+
+    let inner = match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(inner) => inner,
+        None => return Err("expected a string literal".to_string()),
+    };
+
+    let mut text = String::with_capacity(inner.len());
+
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('r') => text.push('\r'),
+            Some('t') => text.push('\t'),
+            Some('0') => text.push('\0'),
+            Some('\\') => text.push('\\'),
+            Some('\'') => text.push('\''),
+            Some('"') => text.push('"'),
+            Some('x') => {
+                let hex: String = chars.by_ref().take(2).collect();
+
+                text.push(u8::from_str_radix(&hex, 16).map_err(|err| err.to_string())? as char);
+            },
+            Some('u') => {
+                let hex: String = chars
+                    .by_ref()
+                    .skip(1)
+                    .take_while(|&c| c != '}')
+                    .filter(|&c| c != '_')
+                    .collect();
+
+                let c = u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid unicode escape `\\u{{{hex}}}`"))?;
+
+                text.push(c);
+            },
+            // a line continuation skips the newline and the leading whitespace of the next line
+            Some('\n') => {
+                let rest = chars.as_str().trim_start();
+
+                chars = rest.chars();
+            },
+            _ => return Err("invalid escape in the string literal".to_string()),
+        }
+    }
+
+    Ok(text)
+}
+
+fn compile_error(message: &str) -> TokenStream {
+    let mut group = TokenStream::new();
+
+    group.extend([TokenTree::Literal(Literal::string(message))]);
+
+    let mut output: TokenStream = "::core::compile_error!".parse().unwrap();
+
+    output.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, group))]);
+
+    output
+}
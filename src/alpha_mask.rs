@@ -0,0 +1,83 @@
+use qrcodegen::{QrCode, QrSegment};
+
+use crate::{
+    classify::make_segments,
+    generate_qrcode_advanced, payload_segments,
+    raster::{to_rgba, RGBA_BYTES_PER_PIXEL},
+    Color, IntoQrPayload, QRCodeError, RenderOptions, Validate,
+};
+
+fn to_alpha_mask_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    options.validate()?;
+
+    // only the coverage of dark modules is kept, so colors, the border and the inversion do not matter
+    let options = RenderOptions {
+        dark_color: Color::BLACK,
+        light_color: Color::TRANSPARENT,
+        quiet_zone_color: None,
+        zone_colors: [None; 4],
+        border: None,
+        inverted: false,
+        ..options.clone()
+    };
+
+    let rgba = to_rgba(qr, size, &options, RGBA_BYTES_PER_PIXEL)?;
+
+    Ok(rgba.chunks_exact(4).map(|pixel| pixel[3]).collect())
+}
+
+/// Encode data to a raw 8-bit alpha mask, one byte per pixel, which is opaque (`255`) where modules are dark and transparent (`0`) elsewhere, so that compositing engines can tint or texture the QR code when blending. The module shape, the module gap and supersampling in the options are applied.
+#[inline]
+pub fn to_alpha_mask<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_alpha_mask_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Encode text to a raw 8-bit alpha mask, one byte per pixel, which is opaque (`255`) where modules are dark and transparent (`0`) elsewhere, so that compositing engines can tint or texture the QR code when blending. The module shape, the module gap and supersampling in the options are applied.
+#[inline]
+pub fn to_alpha_mask_from_str<S: AsRef<str>>(
+    text: S,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_alpha_mask_inner(
+        &generate_qrcode_advanced(
+            &make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Encode segments to a raw 8-bit alpha mask, one byte per pixel, which is opaque (`255`) where modules are dark and transparent (`0`) elsewhere, so that compositing engines can tint or texture the QR code when blending. The module shape, the module gap and supersampling in the options are applied.
+#[inline]
+pub fn to_alpha_mask_from_segments(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_alpha_mask_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        size,
+    )
+}
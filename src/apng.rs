@@ -0,0 +1,91 @@
+use std::{io::Write, time::Duration};
+
+use image::{
+    error::{EncodingError, ImageFormatHint},
+    ImageError, ImageFormat,
+};
+
+use crate::{generate_qrcode, to_image_inner, IntoQrPayload, QRCodeError, QrCodeEcc};
+
+fn to_apng_inner<D: IntoQrPayload, I: IntoIterator<Item = D>, W: Write>(
+    data: I,
+    ecc: QrCodeEcc,
+    size: usize,
+    frame_delay: Duration,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    let frames = data
+        .into_iter()
+        .map(|data| to_image_inner(generate_qrcode(data, ecc)?, size))
+        .collect::<Result<Vec<Vec<u8>>, QRCodeError>>()?;
+
+    if frames.is_empty() {
+        return Err(QRCodeError::invalid_input("data", "an animation needs at least one frame"));
+    }
+
+    let stride = (size + 7) / 8;
+
+    let delay = frame_delay.as_millis().min(u128::from(u16::MAX)) as u16;
+
+    let mut encoder = png::Encoder::new(writer, size as u32, size as u32);
+
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::One);
+    encoder.set_compression(png::Compression::High);
+
+    let result = encoder
+        .set_animated(frames.len() as u32, 0)
+        .and_then(|_| encoder.set_frame_delay(delay, 1000))
+        .and_then(|_| encoder.write_header())
+        .and_then(|mut writer| {
+            let mut bits = vec![0u8; stride * size];
+
+            for frame in frames {
+                bits.fill(0);
+
+                for (i, &luma) in frame.iter().enumerate() {
+                    if luma != 0 {
+                        let (y, x) = (i / size, i % size);
+
+                        bits[y * stride + x / 8] |= 0x80 >> (x % 8);
+                    }
+                }
+
+                writer.write_image_data(&bits)?;
+            }
+
+            writer.finish()
+        });
+
+    result.map_err(|err| {
+        ImageError::Encoding(EncodingError::new(ImageFormatHint::Exact(ImageFormat::Png), err))
+            .into()
+    })
+}
+
+/// Encode every payload to a frame of an endlessly looping, 1-bit APNG image stored in a Vec instance. It suits multi-symbol sequences, such as the parts of a `UrEncoder` or the payloads of `split_into_codes`.
+#[inline]
+pub fn to_apng_to_vec<D: IntoQrPayload, I: IntoIterator<Item = D>>(
+    data: I,
+    ecc: QrCodeEcc,
+    size: usize,
+    frame_delay: Duration,
+) -> Result<Vec<u8>, QRCodeError> {
+    let mut apng = Vec::with_capacity(8192);
+
+    to_apng_inner(data, ecc, size, frame_delay, &mut apng)?;
+
+    Ok(apng)
+}
+
+/// Encode every payload to a frame of an endlessly looping, 1-bit APNG image via a writer. It suits multi-symbol sequences, such as the parts of a `UrEncoder` or the payloads of `split_into_codes`.
+#[inline]
+pub fn to_apng_to_writer<D: IntoQrPayload, I: IntoIterator<Item = D>, W: Write>(
+    data: I,
+    ecc: QrCodeEcc,
+    size: usize,
+    frame_delay: Duration,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_apng_inner(data, ecc, size, frame_delay, writer)
+}
@@ -0,0 +1,16 @@
+use crate::OutputSpec;
+
+/// An image rendered by `render_all` together with its format and size.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Artifact {
+    pub spec: OutputSpec,
+    pub data: Vec<u8>,
+}
+
+/// An image rendered by `render_asset_set` together with its file name, e.g. `logo@2x.png`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamedArtifact {
+    pub name:     String,
+    pub density:  usize,
+    pub artifact: Artifact,
+}
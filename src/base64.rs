@@ -0,0 +1,34 @@
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+#[cfg(feature = "crypto")]
+const URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn encode_with(data: &[u8], s: &mut String, alphabet: &[u8; 64], padding: bool) {
+    s.reserve((data.len() + 2) / 3 * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(alphabet[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else if padding {
+                s.push('=');
+            }
+        }
+    }
+}
+
+/// Encode bytes to standard Base64 with padding.
+#[inline]
+pub(crate) fn encode_to_string(data: &[u8], s: &mut String) {
+    encode_with(data, s, ALPHABET, true)
+}
+
+/// Encode bytes to URL-safe Base64 without padding, as used by JWS.
+#[cfg(feature = "crypto")]
+#[inline]
+pub(crate) fn encode_url_to_string(data: &[u8], s: &mut String) {
+    encode_with(data, s, URL_ALPHABET, false)
+}
@@ -0,0 +1,78 @@
+use bevy_asset::RenderAssetUsages;
+use bevy_image::Image;
+use image::{DynamicImage, RgbaImage};
+use qrcodegen::{QrCode, QrSegment};
+
+use crate::{
+    classify::make_segments,
+    generate_qrcode_advanced, payload_segments,
+    raster::{to_rgba, RGBA_BYTES_PER_PIXEL},
+    IntoQrPayload, QRCodeError, RenderOptions, Validate,
+};
+
+fn to_bevy_image_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Image, QRCodeError> {
+    options.validate()?;
+
+    let rgba = to_rgba(qr, size, options, RGBA_BYTES_PER_PIXEL)?;
+
+    let buffer = RgbaImage::from_raw(size as u32, size as u32, rgba)
+        .ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+    Ok(Image::from_dynamic(DynamicImage::ImageRgba8(buffer), true, RenderAssetUsages::default()))
+}
+
+/// Encode data to a Bevy `Image` (RGBA8, sRGB) which can be added to `Assets<Image>` and used by sprites and UI nodes.
+#[inline]
+pub fn to_bevy_image<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Image, QRCodeError> {
+    to_bevy_image_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Encode text to a Bevy `Image` (RGBA8, sRGB) which can be added to `Assets<Image>` and used by sprites and UI nodes.
+#[inline]
+pub fn to_bevy_image_from_str<S: AsRef<str>>(
+    text: S,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Image, QRCodeError> {
+    to_bevy_image_inner(
+        &generate_qrcode_advanced(
+            &make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Encode segments to a Bevy `Image` (RGBA8, sRGB) which can be added to `Assets<Image>` and used by sprites and UI nodes.
+#[inline]
+pub fn to_bevy_image_from_segments(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Image, QRCodeError> {
+    to_bevy_image_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        size,
+    )
+}
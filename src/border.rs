@@ -0,0 +1,88 @@
+use crate::Color;
+
+/// A decorative frame drawn around the modules. Use `padding` to keep it off the quiet zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Border {
+    pub(crate) width:   usize,
+    pub(crate) radius:  usize,
+    pub(crate) color:   Color,
+    pub(crate) padding: usize,
+}
+
+impl Border {
+    /// Create a border with square corners which touches the modules. `width` is the stroke width in pixels.
+    #[inline]
+    pub const fn new(width: usize, color: Color) -> Self {
+        Border {
+            width,
+            radius: 0,
+            color,
+            padding: 0,
+        }
+    }
+
+    /// Set the radius of the outer corners in pixels.
+    #[inline]
+    pub const fn radius(mut self, radius: usize) -> Self {
+        self.radius = radius;
+
+        self
+    }
+
+    /// Set the gap between the modules and the border in modules. The default value is `0`. To keep the quiet zone clear, the gap needs to be at least as wide as the quiet zone or 4 modules, and the quiet zone needs to be wider than the gap to leave room for the border, see `RenderOptions::warnings`.
+    #[inline]
+    pub const fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+
+        self
+    }
+}
+
+impl Border {
+    /// Calculate the offset and the size of the outer edge of the border, given the offset and the size of the modules and the size of a module.
+    #[inline]
+    pub(crate) fn outer_bounds(
+        &self,
+        data_offset: usize,
+        data_size: usize,
+        point_size: usize,
+    ) -> (f64, f64) {
+        let inset = (self.padding * point_size + self.width) as f64;
+
+        (data_offset as f64 - inset, data_size as f64 + 2.0 * inset)
+    }
+
+    /// Check whether a point is covered by the border whose outer edge starts at `offset` with the size `size`.
+    #[cfg(feature = "image")]
+    pub(crate) fn covers(&self, offset: f64, size: f64, x: f64, y: f64) -> bool {
+        let width = self.width as f64;
+        let radius = self.radius as f64;
+
+        inside_rounded_square(offset, size, radius, x, y)
+            && !inside_rounded_square(
+                offset + width,
+                size - 2.0 * width,
+                (radius - width).max(0.0),
+                x,
+                y,
+            )
+    }
+}
+
+/// Check whether a point is inside the square whose corners are rounded with `radius`.
+#[cfg(any(feature = "image", feature = "tiff"))]
+pub(crate) fn inside_rounded_square(offset: f64, size: f64, radius: f64, x: f64, y: f64) -> bool {
+    let end = offset + size;
+
+    if x < offset || y < offset || x >= end || y >= end {
+        return false;
+    }
+
+    let radius = radius.min(size / 2.0);
+
+    // the nearest point on the square shrunk by the radius
+    let cx = x.clamp(offset + radius, end - radius);
+    let cy = y.clamp(offset + radius, end - radius);
+
+    (x - cx).powi(2) + (y - cy).powi(2) <= radius.powi(2)
+}
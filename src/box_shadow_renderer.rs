@@ -0,0 +1,64 @@
+use std::io::Write;
+
+use qrcodegen::QrCode;
+
+use crate::{layout, zone::to_zone_map, QRCodeError, RenderOptions};
+
+/// Render a QR code to an HTML snippet of a single `div`, whose own box is the top-left corner of the quiet zone and whose `box-shadow` list paints the rest. Only the colors in the options are used.
+pub(crate) fn to_box_shadow_html_inner<W: Write>(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    mut writer: W,
+) -> Result<(), QRCodeError> {
+    let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+    let s = qr.size();
+
+    let (quiet_zone_color, light_color, _) = options.colors();
+
+    let zone_colors = options.zone_colors();
+
+    let zones = to_zone_map(qr);
+
+    let rest = size - point_size;
+
+    writer.write_fmt(format_args!(
+        "<div style=\"width:{point_size}px;height:{point_size}px;margin:0 {rest}px {rest}px \
+         0;background:{};box-shadow:",
+        quiet_zone_color.to_css()
+    ))?;
+
+    // earlier shadows are painted on top of later ones
+    for i in 0..s {
+        for j in 0..s {
+            if qr.get_module(j, i) {
+                let x = j as usize * point_size + margin;
+                let y = i as usize * point_size + margin;
+
+                let color = zone_colors[zones[(i * s + j) as usize] as usize];
+
+                writer.write_fmt(format_args!("{x}px {y}px {},", color.to_css()))?;
+            }
+        }
+    }
+
+    // shadows whose spreads grow the box to the data area and the whole image
+    let data_spread = ((s - 1) as usize * point_size) as f64 / 2.0;
+    let data_shift = margin as f64 + data_spread;
+
+    let image_spread = rest as f64 / 2.0;
+
+    writer.write_fmt(format_args!(
+        "{data_shift}px {data_shift}px 0 {data_spread}px {},{image_spread}px {image_spread}px 0 \
+         {image_spread}px {}",
+        light_color.to_css(),
+        quiet_zone_color.to_css()
+    ))?;
+
+    writer.write_all(b"\"></div>")?;
+
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+/*!
+Helpers for build scripts (`build.rs`) which bake QR code images into the build output.
+
+```rust,ignore
+// build.rs
+fn main() {
+    qrcode_generator::build::render_manifest("qrcodes.csv").unwrap();
+}
+```
+
+The manifest is CSV text whose header names the columns `file`, `size`, `ecc` and `payload`. The format of each image is detected from the extension of `file`, see `OutputFormat::from_extension`. File names must be relative paths without `..` components.
+
+```text
+file,size,ecc,payload
+homepage.svg,256,M,https://magiclen.org
+support.png,512,H,mailto:len@magiclen.org
+```
+
+The images can then be included with `include_bytes!(concat!(env!("OUT_DIR"), "/homepage.svg"))`.
+*/
+
+use std::{
+    env, fs,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{csv::parse_csv, to_file_from_str, Ecc, QRCodeError, QrCodeEcc};
+
+/// Get the `OUT_DIR` directory which Cargo provides to build scripts.
+pub fn out_dir() -> Result<PathBuf, QRCodeError> {
+    env::var_os("OUT_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| QRCodeError::invalid_input("OUT_DIR", "the environment variable is not set"))
+}
+
+/// Join a file name to a directory, rejecting absolute paths and `..` components which would escape the directory.
+fn join_file_name(dir: &Path, file_name: &Path) -> Result<PathBuf, QRCodeError> {
+    if file_name
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(QRCodeError::invalid_input(
+            "file",
+            format!("{:?} is not a relative path inside the directory", file_name),
+        ));
+    }
+
+    Ok(dir.join(file_name))
+}
+
+/// Tell Cargo to rerun the build script when the file changes.
+#[inline]
+pub fn rerun_if_changed<P: AsRef<Path>>(path: P) {
+    println!("cargo:rerun-if-changed={}", path.as_ref().display());
+}
+
+/// Render text to an image in `OUT_DIR`. The format is detected from the extension of `file_name`, which must be a relative path without `..` components. Returns the path of the image.
+pub fn render_to_out_dir<S: AsRef<str>, P: AsRef<Path>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    file_name: P,
+) -> Result<PathBuf, QRCodeError> {
+    let path = join_file_name(&out_dir()?, file_name.as_ref())?;
+
+    to_file_from_str(text, ecc, size, &path)?;
+
+    Ok(path)
+}
+
+/// Render every entry of a CSV manifest to an image in `OUT_DIR`, and tell Cargo to rerun the build script when the manifest changes. Returns the paths of the images.
+#[inline]
+pub fn render_manifest<P: AsRef<Path>>(manifest: P) -> Result<Vec<PathBuf>, QRCodeError> {
+    render_manifest_to_dir(manifest, out_dir()?)
+}
+
+/// Render every entry of a CSV manifest to an image in a directory, and tell Cargo to rerun the build script when the manifest changes. Returns the paths of the images.
+pub fn render_manifest_to_dir<P: AsRef<Path>, D: AsRef<Path>>(
+    manifest: P,
+    dir: D,
+) -> Result<Vec<PathBuf>, QRCodeError> {
+    let manifest = manifest.as_ref();
+    let dir = dir.as_ref();
+
+    rerun_if_changed(manifest);
+
+    let mut rows = parse_csv(&fs::read_to_string(manifest)?)?.into_iter();
+
+    let header = match rows.next() {
+        Some(header) => header,
+        None => return Ok(Vec::new()),
+    };
+
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|field| field == name)
+            .ok_or_else(|| QRCodeError::UnknownField(name.to_string()))
+    };
+
+    let (file, size, ecc, payload) =
+        (column("file")?, column("size")?, column("ecc")?, column("payload")?);
+
+    rows.map(|row| {
+        if row.len() != header.len() {
+            return Err(QRCodeError::InvalidCsv);
+        }
+
+        let size = row[size].trim().parse::<usize>().map_err(|err| {
+            QRCodeError::invalid_input("size", format!("{:?} is not a size: {err}", row[size]))
+        })?;
+
+        let ecc = row[ecc]
+            .parse::<Ecc>()
+            .map_err(|err| QRCodeError::invalid_input("ecc", err.to_string()))?;
+
+        let path = join_file_name(dir, Path::new(&row[file]))?;
+
+        to_file_from_str(&row[payload], ecc.into(), size, &path)?;
+
+        Ok(path)
+    })
+    .collect()
+}
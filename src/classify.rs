@@ -0,0 +1,81 @@
+use qrcodegen::{BitBuffer, QrSegment, QrSegmentMode};
+
+/// The characters of the alphanumeric mode, in the order of their values.
+const ALPHANUMERIC_CHARSET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Bytes which cannot be encoded in the alphanumeric mode.
+const NOT_ALPHANUMERIC: u8 = u8::MAX;
+
+/// The alphanumeric value of every byte, or `NOT_ALPHANUMERIC`. Digits are the values below `10`.
+const ALPHANUMERIC_VALUES: [u8; 256] = {
+    let mut values = [NOT_ALPHANUMERIC; 256];
+
+    let mut i = 0;
+
+    while i < ALPHANUMERIC_CHARSET.len() {
+        values[ALPHANUMERIC_CHARSET[i] as usize] = i as u8;
+
+        i += 1;
+    }
+
+    values
+};
+
+/// Check whether a byte can be encoded in the alphanumeric mode.
+#[inline]
+pub(crate) fn is_alphanumeric(b: u8) -> bool {
+    ALPHANUMERIC_VALUES[b as usize] != NOT_ALPHANUMERIC
+}
+
+/// Make a single segment of data in the most compact mode among numeric, alphanumeric and byte, the same as `QrSegment::make_segments` makes for text and `QrSegment::make_bytes` makes for other data.
+///
+/// The mode is decided in a single pass over the bytes with a lookup table. UTF-8 validation is not needed, because numeric and alphanumeric data is always ASCII and the byte mode takes any bytes.
+pub(crate) fn make_segments(data: &[u8]) -> Vec<QrSegment> {
+    if data.is_empty() {
+        return Vec::new();
+    }
+
+    let mut numeric = true;
+
+    for &byte in data {
+        let value = ALPHANUMERIC_VALUES[byte as usize];
+
+        if value == NOT_ALPHANUMERIC {
+            return vec![QrSegment::make_bytes(data)];
+        }
+
+        numeric &= value < 10;
+    }
+
+    let segment = if numeric { make_numeric(data) } else { make_alphanumeric(data) };
+
+    vec![segment]
+}
+
+/// Encode digits, three per 10 bits.
+fn make_numeric(digits: &[u8]) -> QrSegment {
+    let mut bits = BitBuffer(Vec::with_capacity(digits.len() * 3 + (digits.len() + 2) / 3));
+
+    for chunk in digits.chunks(3) {
+        let value = chunk.iter().fold(0u32, |value, &digit| value * 10 + u32::from(digit - b'0'));
+
+        bits.append_bits(value, chunk.len() as u8 * 3 + 1);
+    }
+
+    QrSegment::new(QrSegmentMode::Numeric, digits.len(), bits.0)
+}
+
+/// Encode alphanumeric characters, two per 11 bits.
+fn make_alphanumeric(text: &[u8]) -> QrSegment {
+    let mut bits = BitBuffer(Vec::with_capacity(text.len() * 5 + (text.len() + 1) / 2));
+
+    for chunk in text.chunks(2) {
+        let value = chunk
+            .iter()
+            .fold(0u32, |value, &byte| value * 45 + u32::from(ALPHANUMERIC_VALUES[byte as usize]));
+
+        bits.append_bits(value, if chunk.len() == 2 { 11 } else { 6 });
+    }
+
+    QrSegment::new(QrSegmentMode::Alphanumeric, text.len(), bits.0)
+}
@@ -0,0 +1,67 @@
+/// A CMYK ink combination. Each component is a percentage from `0` to `100`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CmykColor {
+    pub(crate) c: u8,
+    pub(crate) m: u8,
+    pub(crate) y: u8,
+    pub(crate) k: u8,
+}
+
+impl CmykColor {
+    /// No ink, i.e. the paper color.
+    pub const PAPER: CmykColor = CmykColor::new(0, 0, 0, 0);
+    /// 100% black ink only.
+    pub const PURE_BLACK: CmykColor = CmykColor::new(0, 0, 0, 100);
+    /// A commonly used rich black, C60 M40 Y40 K100.
+    pub const RICH_BLACK: CmykColor = CmykColor::new(60, 40, 40, 100);
+
+    /// Create a CMYK color. Percentages larger than `100` are treated as `100`.
+    #[inline]
+    pub const fn new(c: u8, m: u8, y: u8, k: u8) -> Self {
+        const fn clamp(v: u8) -> u8 {
+            if v > 100 {
+                100
+            } else {
+                v
+            }
+        }
+
+        CmykColor {
+            c: clamp(c), m: clamp(m), y: clamp(y), k: clamp(k)
+        }
+    }
+
+    /// The percentage of cyan ink.
+    #[inline]
+    pub const fn c(self) -> u8 {
+        self.c
+    }
+
+    /// The percentage of magenta ink.
+    #[inline]
+    pub const fn m(self) -> u8 {
+        self.m
+    }
+
+    /// The percentage of yellow ink.
+    #[inline]
+    pub const fn y(self) -> u8 {
+        self.y
+    }
+
+    /// The percentage of black ink.
+    #[inline]
+    pub const fn k(self) -> u8 {
+        self.k
+    }
+
+    /// Convert to 8-bit samples (`0` to `255`).
+    #[inline]
+    pub const fn to_u8_samples(self) -> [u8; 4] {
+        const fn scale(v: u8) -> u8 {
+            ((v as u16 * 255 + 50) / 100) as u8
+        }
+
+        [scale(self.c), scale(self.m), scale(self.y), scale(self.k)]
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt::Write;
+
+/// An sRGB color with an alpha channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    /// Create an opaque color.
+    #[inline]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color::rgba(r, g, b, 255)
+    }
+
+    #[inline]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color {
+            r,
+            g,
+            b,
+            a,
+        }
+    }
+
+    #[inline]
+    pub const fn to_array(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+impl Color {
+    /// Calculate the approximate relative luminance in the range 0 to 1.
+    #[inline]
+    pub(crate) fn luminance(self) -> f64 {
+        (0.2126 * f64::from(self.r) + 0.7152 * f64::from(self.g) + 0.0722 * f64::from(self.b))
+            / 255.0
+    }
+
+    /// Format to the shortest hex notation without alpha, e.g. `#FFF` or `#1A2B3C`.
+    pub(crate) fn to_hex(self) -> String {
+        let mut s = String::with_capacity(7);
+
+        s.push('#');
+
+        if [self.r, self.g, self.b].iter().all(|v| v >> 4 == v & 0xF) {
+            let _ = write!(s, "{:X}{:X}{:X}", self.r & 0xF, self.g & 0xF, self.b & 0xF);
+        } else {
+            let _ = write!(s, "{:02X}{:02X}{:02X}", self.r, self.g, self.b);
+        }
+
+        s
+    }
+
+    /// Get the alpha channel as an opacity in the range 0 to 1, rounded to 3 decimal places.
+    #[inline]
+    pub(crate) fn opacity(self) -> f64 {
+        (f64::from(self.a) / 255.0 * 1000.0).round() / 1000.0
+    }
+
+    /// Format to a CSS color, e.g. `#FFF` or `rgba(0,0,0,0.5)`.
+    pub(crate) fn to_css(self) -> String {
+        if self.a < 255 {
+            format!("rgba({},{},{},{})", self.r, self.g, self.b, self.opacity())
+        } else {
+            self.to_hex()
+        }
+    }
+
+    /// Format to the SVG attributes of a fill color, e.g. `fill="#FFF"` or `fill="#000" fill-opacity="0.5"`.
+    pub(crate) fn to_svg_fill(self) -> String {
+        let mut s = format!("fill=\"{}\"", self.to_hex());
+
+        if self.a < 255 {
+            let _ = write!(s, " fill-opacity=\"{}\"", self.opacity());
+        }
+
+        s
+    }
+}
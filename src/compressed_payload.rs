@@ -0,0 +1,46 @@
+use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec_with_limit};
+
+use crate::DecompressPayloadError;
+
+/// The header of compressed payloads, `QZ` followed by the format version.
+pub const COMPRESSED_PAYLOAD_MARKER: [u8; 3] = [b'Q', b'Z', 1];
+
+/// The maximum length of decompressed payloads, to protect against decompression bombs.
+pub const MAX_DECOMPRESSED_PAYLOAD_LEN: usize = 1024 * 1024;
+
+/// Compress a payload with deflate and prepend `COMPRESSED_PAYLOAD_MARKER`, so that large JSON blobs and other redundant data can fit in a QR code. Encode the result as binary data.
+#[inline]
+pub fn compress_payload<D: AsRef<[u8]>>(data: D) -> Vec<u8> {
+    let compressed = compress_to_vec(data.as_ref(), 10);
+
+    let mut payload = Vec::with_capacity(COMPRESSED_PAYLOAD_MARKER.len() + compressed.len());
+
+    payload.extend_from_slice(&COMPRESSED_PAYLOAD_MARKER);
+    payload.extend_from_slice(&compressed);
+
+    payload
+}
+
+/// Check whether a decoded payload starts with `COMPRESSED_PAYLOAD_MARKER`.
+#[inline]
+pub fn is_compressed_payload<D: AsRef<[u8]>>(data: D) -> bool {
+    data.as_ref().starts_with(&COMPRESSED_PAYLOAD_MARKER)
+}
+
+/// Decompress a payload created by `compress_payload`.
+pub fn decompress_payload<D: AsRef<[u8]>>(data: D) -> Result<Vec<u8>, DecompressPayloadError> {
+    let data = data.as_ref();
+
+    if !is_compressed_payload(data) {
+        return Err(DecompressPayloadError::MissingMarker);
+    }
+
+    decompress_to_vec_with_limit(
+        &data[COMPRESSED_PAYLOAD_MARKER.len()..],
+        MAX_DECOMPRESSED_PAYLOAD_LEN,
+    )
+    .map_err(|err| match err.status {
+        miniz_oxide::inflate::TINFLStatus::HasMoreOutput => DecompressPayloadError::TooLarge,
+        _ => DecompressPayloadError::InvalidData,
+    })
+}
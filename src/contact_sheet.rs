@@ -0,0 +1,229 @@
+#[cfg(feature = "ab_glyph")]
+use crate::Font;
+use crate::{
+    check_image_size, generate_qrcode,
+    raster::{encode_png, to_rgba, PNG_BYTES_PER_PIXEL, RGBA_BYTES_PER_PIXEL},
+    render_options::DEFAULT_RENDER_OPTIONS,
+    Color, IntoQrPayload, OutputLimit, QRCodeError, QrCodeEcc, Validate,
+};
+
+/// The layout of a contact sheet, i.e. a grid of QR codes with optional labels below them in one PNG image.
+#[derive(Debug, Clone)]
+pub struct ContactSheet {
+    pub(crate) columns:      usize,
+    pub(crate) cell_size:    usize,
+    pub(crate) gap:          usize,
+    pub(crate) font_size:    usize,
+    pub(crate) ecc:          QrCodeEcc,
+    pub(crate) background:   Color,
+    pub(crate) output_limit: OutputLimit,
+    #[cfg(feature = "ab_glyph")]
+    pub(crate) font:         Option<Font>,
+}
+
+impl ContactSheet {
+    /// Create a grid of `columns` columns whose cells are `cell_size` × `cell_size` QR codes.
+    #[inline]
+    pub fn new(columns: usize, cell_size: usize) -> Self {
+        ContactSheet {
+            columns,
+            cell_size,
+            gap: 16,
+            font_size: 16,
+            ecc: QrCodeEcc::Medium,
+            background: Color::WHITE,
+            output_limit: OutputLimit::UNLIMITED,
+            #[cfg(feature = "ab_glyph")]
+            font: None,
+        }
+    }
+
+    /// Set the space between cells and around the grid in pixels. The default value is `16`.
+    #[inline]
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+
+        self
+    }
+
+    /// Set the font size of labels in pixels. The default value is `16`.
+    #[inline]
+    pub fn font_size(mut self, font_size: usize) -> Self {
+        self.font_size = font_size;
+
+        self
+    }
+
+    /// Set the error correction level. The default value is `QrCodeEcc::Medium`.
+    #[inline]
+    pub fn ecc(mut self, ecc: QrCodeEcc) -> Self {
+        self.ecc = ecc;
+
+        self
+    }
+
+    /// Set the background color of the sheet. The default value is `Color::WHITE`.
+    #[inline]
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+
+        self
+    }
+
+    /// Set the limits of the memory needed by the sheet. There are no limits by default.
+    #[inline]
+    pub fn output_limit(mut self, output_limit: OutputLimit) -> Self {
+        self.output_limit = output_limit;
+
+        self
+    }
+
+    /// Set the font used to draw labels.
+    #[cfg(feature = "ab_glyph")]
+    #[inline]
+    pub fn font(mut self, font: Option<Font>) -> Self {
+        self.font = font;
+
+        self
+    }
+}
+
+impl Validate for ContactSheet {
+    fn validate(&self) -> Result<(), QRCodeError> {
+        if self.columns == 0 {
+            return Err(QRCodeError::invalid_input("columns", "there must be at least one column"));
+        }
+
+        if self.font_size == 0 {
+            return Err(QRCodeError::invalid_input("font_size", "the font size must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Compute the length of `count` cells of `cell_size` pixels with `gap` pixels between and around them.
+#[inline]
+fn grid_length(count: usize, cell_size: usize, gap: usize) -> Result<usize, QRCodeError> {
+    cell_size
+        .checked_add(gap)
+        .and_then(|length| length.checked_mul(count))
+        .and_then(|length| length.checked_add(gap))
+        .ok_or(QRCodeError::ImageSizeTooLarge)
+}
+
+fn render_contact_sheet_inner<D: IntoQrPayload, S: AsRef<str>>(
+    items: Vec<(D, Option<S>)>,
+    sheet: &ContactSheet,
+) -> Result<Vec<u8>, QRCodeError> {
+    sheet.validate()?;
+
+    let labeled = items.iter().any(|(_, label)| label.is_some());
+
+    let label_height = if labeled { sheet.font_size.saturating_mul(3) / 2 } else { 0 };
+
+    let columns = sheet.columns.min(items.len()).max(1);
+    let rows = (items.len() + columns - 1) / columns;
+
+    let cell_width = sheet.cell_size;
+    let cell_height =
+        sheet.cell_size.checked_add(label_height).ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+    let width = grid_length(columns, cell_width, sheet.gap)?;
+    let height = grid_length(rows, cell_height, sheet.gap)?;
+
+    check_image_size(width.max(height))?;
+
+    sheet.output_limit.check_dimensions(width, height, PNG_BYTES_PER_PIXEL)?;
+
+    let length = (width * height).checked_mul(4).ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+    let mut rgba = Vec::with_capacity(length);
+
+    for _ in 0..(width * height) {
+        rgba.extend_from_slice(&sheet.background.to_array());
+    }
+
+    let mut labels = Vec::new();
+
+    for (i, (data, label)) in items.into_iter().enumerate() {
+        let x = sheet.gap + (i % columns) * (cell_width + sheet.gap);
+        let y = sheet.gap + (i / columns) * (cell_height + sheet.gap);
+
+        let qr_rgba = to_rgba(
+            &generate_qrcode(data, sheet.ecc)?,
+            sheet.cell_size,
+            &DEFAULT_RENDER_OPTIONS,
+            RGBA_BYTES_PER_PIXEL,
+        )?;
+
+        for row in 0..sheet.cell_size {
+            let src = row * sheet.cell_size * 4;
+            let dst = ((y + row) * width + x) * 4;
+
+            rgba[dst..(dst + sheet.cell_size * 4)]
+                .copy_from_slice(&qr_rgba[src..(src + sheet.cell_size * 4)]);
+        }
+
+        if let Some(label) = label {
+            if !label.as_ref().is_empty() {
+                labels.push((x, y + sheet.cell_size + sheet.font_size, label));
+            }
+        }
+    }
+
+    if !labels.is_empty() {
+        #[cfg(feature = "ab_glyph")]
+        {
+            let font = sheet.font.as_ref().ok_or(QRCodeError::MissingFont)?;
+
+            for (x, y, label) in labels {
+                crate::text_raster::draw_text(
+                    &mut rgba,
+                    width,
+                    height,
+                    font,
+                    sheet.font_size as f32,
+                    x as f32,
+                    y as f32,
+                    label.as_ref(),
+                    Color::BLACK,
+                );
+            }
+        }
+
+        #[cfg(not(feature = "ab_glyph"))]
+        return Err(QRCodeError::MissingFont);
+    }
+
+    let mut png = Vec::with_capacity(width * height / 4);
+
+    encode_png(rgba, width, height, None, &mut png)?;
+
+    Ok(png)
+}
+
+/// Render a batch of QR codes into one PNG image, arranged in a grid from left to right and top to bottom, for reviewing bulk generations at a glance.
+#[inline]
+pub fn render_contact_sheet<D: IntoQrPayload, I: IntoIterator<Item = D>>(
+    data: I,
+    sheet: &ContactSheet,
+) -> Result<Vec<u8>, QRCodeError> {
+    render_contact_sheet_inner(data.into_iter().map(|data| (data, None::<&str>)).collect(), sheet)
+}
+
+/// Render a batch of QR codes with a label below each one into one PNG image, arranged in a grid from left to right and top to bottom. Drawing labels needs the `ab_glyph` feature and a font.
+#[inline]
+pub fn render_labeled_contact_sheet<
+    D: IntoQrPayload,
+    S: AsRef<str>,
+    I: IntoIterator<Item = (D, S)>,
+>(
+    items: I,
+    sheet: &ContactSheet,
+) -> Result<Vec<u8>, QRCodeError> {
+    render_contact_sheet_inner(
+        items.into_iter().map(|(data, label)| (data, Some(label))).collect(),
+        sheet,
+    )
+}
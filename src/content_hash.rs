@@ -0,0 +1,77 @@
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str, IntoQrPayload,
+    QRCodeError,
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// A 64-bit FNV-1a hasher, whose output does not depend on the platform, the Rust version or a random seed.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// Hash the number of modules on each side followed by the modules, row by row, packed into bytes with the first module in the most significant bit.
+fn hash_modules(size: usize, mut module: impl FnMut(usize, usize) -> bool) -> u64 {
+    let mut hasher = Fnv1a(FNV_OFFSET_BASIS);
+
+    hasher.write(&(size as u32).to_le_bytes());
+
+    for y in 0..size {
+        let mut byte = 0u8;
+
+        for x in 0..size {
+            if module(x, y) {
+                byte |= 0x80 >> (x % 8);
+            }
+
+            if x % 8 == 7 || x == size - 1 {
+                hasher.write(&[byte]);
+
+                byte = 0;
+            }
+        }
+    }
+
+    hasher.0
+}
+
+#[inline]
+pub(crate) fn content_hash_inner(qr: &QrCode) -> u64 {
+    hash_modules(qr.size() as usize, |x, y| qr.get_module(x as i32, y as i32))
+}
+
+/// Compute a stable 64-bit hash of the modules of a QR code matrix, the same as `content_hash` computes for the data of the matrix. Malformed matrices whose rows are shorter than the number of rows are padded with light modules.
+pub fn matrix_content_hash<R: AsRef<[bool]>>(matrix: &[R]) -> u64 {
+    hash_modules(matrix.len(), |x, y| matrix[y].as_ref().get(x).copied().unwrap_or(false))
+}
+
+/// Encode data and compute a stable 64-bit hash of the modules, so that caches and deduplication can key on the generated QR code instead of rendered images. The hash does not change between platforms, runs and releases of this crate.
+#[inline]
+pub fn content_hash<D: IntoQrPayload>(data: D, ecc: QrCodeEcc) -> Result<u64, QRCodeError> {
+    Ok(content_hash_inner(&generate_qrcode(data, ecc)?))
+}
+
+/// Encode text and compute a stable 64-bit hash of the modules, so that caches and deduplication can key on the generated QR code instead of rendered images. The hash does not change between platforms, runs and releases of this crate.
+#[inline]
+pub fn content_hash_from_str<S: AsRef<str>>(text: S, ecc: QrCodeEcc) -> Result<u64, QRCodeError> {
+    Ok(content_hash_inner(&generate_qrcode_from_str(text, ecc)?))
+}
+
+/// Encode segments and compute a stable 64-bit hash of the modules, so that caches and deduplication can key on the generated QR code instead of rendered images. The hash does not change between platforms, runs and releases of this crate.
+#[inline]
+pub fn content_hash_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+) -> Result<u64, QRCodeError> {
+    Ok(content_hash_inner(&generate_qrcode_from_segments(segments, ecc)?))
+}
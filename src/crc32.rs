@@ -0,0 +1,36 @@
+const fn make_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+
+    let mut i = 0;
+
+    while i < 256 {
+        let mut c = i as u32;
+
+        let mut k = 0;
+
+        while k < 8 {
+            c = if c & 1 == 1 { 0xEDB88320 ^ (c >> 1) } else { c >> 1 };
+
+            k += 1;
+        }
+
+        table[i] = c;
+
+        i += 1;
+    }
+
+    table
+}
+
+static TABLE: [u32; 256] = make_table();
+
+/// The CRC-32 (ISO-HDLC) checksum which is used by PNG, zlib, etc.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut c = 0xFFFFFFFFu32;
+
+    for &b in data {
+        c = TABLE[((c ^ b as u32) & 0xFF) as usize] ^ (c >> 8);
+    }
+
+    c ^ 0xFFFFFFFF
+}
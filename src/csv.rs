@@ -0,0 +1,122 @@
+use crate::QRCodeError;
+
+/// Parse CSV text (RFC 4180) into rows of fields. Fields may be quoted with `"`, and a `""` in a quoted field is a literal `"`. Both CRLF and LF line breaks are accepted.
+pub(crate) fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, QRCodeError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+
+    let mut chars = text.chars().peekable();
+
+    // whether the current row has any content, so that blank lines can be skipped
+    let mut row_started = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => {
+                row_started = true;
+
+                loop {
+                    match chars.next() {
+                        Some('"') => {
+                            if chars.peek() == Some(&'"') {
+                                chars.next();
+
+                                field.push('"');
+                            } else {
+                                break;
+                            }
+                        },
+                        Some(c) => field.push(c),
+                        None => return Err(QRCodeError::InvalidCsv),
+                    }
+                }
+
+                match chars.peek() {
+                    Some(',' | '\r' | '\n') | None => (),
+                    Some(_) => return Err(QRCodeError::InvalidCsv),
+                }
+            },
+            ',' => {
+                row_started = true;
+
+                row.push(std::mem::take(&mut field));
+            },
+            '\r' if chars.peek() == Some(&'\n') => (),
+            '\n' => {
+                if row_started {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+
+                    row_started = false;
+                }
+            },
+            c => {
+                row_started = true;
+
+                field.push(c);
+            },
+        }
+    }
+
+    if row_started {
+        row.push(field);
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+/// Replace `{name}` placeholders in a template with the values of fields. `{{` and `}}` are literal braces.
+pub(crate) fn fill_template<K: AsRef<str>, V: AsRef<str>>(
+    template: &str,
+    fields: &[(K, V)],
+) -> Result<String, QRCodeError> {
+    let mut result = String::with_capacity(template.len());
+
+    let mut chars = template.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                let rest = chars.as_str();
+
+                if let Some(rest) = rest.strip_prefix('{') {
+                    result.push('{');
+
+                    chars = rest.chars();
+
+                    continue;
+                }
+
+                let end = rest.find('}').ok_or(QRCodeError::InvalidTemplate)?;
+
+                let name = &rest[..end];
+
+                let (_, value) = fields
+                    .iter()
+                    .find(|(k, _)| k.as_ref() == name)
+                    .ok_or_else(|| QRCodeError::UnknownField(name.to_string()))?;
+
+                result.push_str(value.as_ref());
+
+                chars = rest[(end + 1)..].chars();
+            },
+            '}' => {
+                let rest = chars.as_str();
+
+                match rest.strip_prefix('}') {
+                    Some(rest) => {
+                        result.push('}');
+
+                        chars = rest.chars();
+                    },
+                    None => return Err(QRCodeError::InvalidTemplate),
+                }
+            },
+            c => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,29 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when decompressing a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecompressPayloadError {
+    MissingMarker,
+    InvalidData,
+    TooLarge,
+}
+
+impl Display for DecompressPayloadError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            DecompressPayloadError::MissingMarker => {
+                f.write_str("the payload does not start with the compressed payload marker")
+            },
+            DecompressPayloadError::InvalidData => f.write_str("the compressed data is corrupted"),
+            DecompressPayloadError::TooLarge => {
+                f.write_str("the decompressed payload exceeds the maximum length")
+            },
+        }
+    }
+}
+
+impl Error for DecompressPayloadError {}
@@ -0,0 +1,89 @@
+/// Methods to turn shades into black and white pixels for 1-bit outputs, such as e-ink and thermal printers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Dithering {
+    /// Every pixel becomes white if its luminance is at least 0.5.
+    #[default]
+    Threshold,
+    /// Compare pixels with a 4 × 4 Bayer matrix, which gives a regular pattern.
+    Ordered,
+    /// Diffuse the error of every pixel to its unprocessed neighbors with Floyd–Steinberg weights.
+    FloydSteinberg,
+}
+
+/// The thresholds of ordered dithering, in sixteenths.
+#[cfg(feature = "image")]
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+impl Dithering {
+    /// Convert luminances in the range 0 to 1 to rows of packed bits whose set bits are white. Pure black and pure white pixels are kept as they are by every method.
+    #[cfg(feature = "image")]
+    pub(crate) fn to_bits(self, luminances: &[f64], width: usize, height: usize) -> Vec<u8> {
+        let stride = (width + 7) / 8;
+
+        let mut bits = vec![0u8; stride * height];
+
+        let mut set_white = |x: usize, y: usize| bits[y * stride + x / 8] |= 0x80 >> (x % 8);
+
+        match self {
+            Dithering::Threshold => {
+                for (i, &luminance) in luminances.iter().enumerate() {
+                    if luminance >= 0.5 {
+                        set_white(i % width, i / width);
+                    }
+                }
+            },
+            Dithering::Ordered => {
+                for (i, &luminance) in luminances.iter().enumerate() {
+                    let (x, y) = (i % width, i / width);
+
+                    let threshold = (f64::from(BAYER_4X4[y % 4][x % 4]) + 0.5) / 16.0;
+
+                    if luminance >= threshold {
+                        set_white(x, y);
+                    }
+                }
+            },
+            Dithering::FloydSteinberg => {
+                // the errors of the current row and the next row, with a pixel of padding on both sides
+                let mut current = vec![0f64; width + 2];
+                let mut next = vec![0f64; width + 2];
+
+                for y in 0..height {
+                    for x in 0..width {
+                        let luminance = luminances[y * width + x];
+
+                        // pure pixels, such as the insides of modules, take no error, so the edges of modules stay crisp
+                        if luminance <= 0.0 || luminance >= 1.0 {
+                            if luminance >= 1.0 {
+                                set_white(x, y);
+                            }
+
+                            continue;
+                        }
+
+                        let value = luminance + current[x + 1];
+
+                        let white = value >= 0.5;
+
+                        if white {
+                            set_white(x, y);
+                        }
+
+                        let error = value - if white { 1.0 } else { 0.0 };
+
+                        current[x + 2] += error * 7.0 / 16.0;
+                        next[x] += error * 3.0 / 16.0;
+                        next[x + 1] += error * 5.0 / 16.0;
+                        next[x + 2] += error / 16.0;
+                    }
+
+                    std::mem::swap(&mut current, &mut next);
+
+                    next.fill(0.0);
+                }
+            },
+        }
+
+        bits
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::{ParseEccError, QrCodeEcc};
+
+/// The error correction level which can be parsed from strings (`"L"`, `"medium"`, etc.) and integers (`0` to `3`), and converted to/from `QrCodeEcc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Ecc {
+    /// About 7% erroneous codewords can be tolerated.
+    Low,
+    /// About 15% erroneous codewords can be tolerated.
+    Medium,
+    /// About 25% erroneous codewords can be tolerated.
+    Quartile,
+    /// About 30% erroneous codewords can be tolerated.
+    High,
+}
+
+impl Ecc {
+    /// Get the single-letter name (`L`, `M`, `Q` or `H`).
+    #[inline]
+    pub const fn as_letter(self) -> char {
+        match self {
+            Ecc::Low => 'L',
+            Ecc::Medium => 'M',
+            Ecc::Quartile => 'Q',
+            Ecc::High => 'H',
+        }
+    }
+}
+
+impl From<Ecc> for QrCodeEcc {
+    #[inline]
+    fn from(ecc: Ecc) -> Self {
+        match ecc {
+            Ecc::Low => QrCodeEcc::Low,
+            Ecc::Medium => QrCodeEcc::Medium,
+            Ecc::Quartile => QrCodeEcc::Quartile,
+            Ecc::High => QrCodeEcc::High,
+        }
+    }
+}
+
+impl From<QrCodeEcc> for Ecc {
+    #[inline]
+    fn from(ecc: QrCodeEcc) -> Self {
+        match ecc {
+            QrCodeEcc::Low => Ecc::Low,
+            QrCodeEcc::Medium => Ecc::Medium,
+            QrCodeEcc::Quartile => Ecc::Quartile,
+            QrCodeEcc::High => Ecc::High,
+        }
+    }
+}
+
+impl FromStr for Ecc {
+    type Err = ParseEccError;
+
+    /// Case-insensitively parse `L`, `M`, `Q`, `H`, `Low`, `Medium`, `Quartile` or `High`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.eq_ignore_ascii_case("l") || s.eq_ignore_ascii_case("low") {
+            Ok(Ecc::Low)
+        } else if s.eq_ignore_ascii_case("m") || s.eq_ignore_ascii_case("medium") {
+            Ok(Ecc::Medium)
+        } else if s.eq_ignore_ascii_case("q") || s.eq_ignore_ascii_case("quartile") {
+            Ok(Ecc::Quartile)
+        } else if s.eq_ignore_ascii_case("h") || s.eq_ignore_ascii_case("high") {
+            Ok(Ecc::High)
+        } else {
+            Err(ParseEccError::InvalidName)
+        }
+    }
+}
+
+impl TryFrom<u8> for Ecc {
+    type Error = ParseEccError;
+
+    /// Convert `0`, `1`, `2` or `3` to `Low`, `Medium`, `Quartile` or `High`.
+    #[inline]
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            0 => Ok(Ecc::Low),
+            1 => Ok(Ecc::Medium),
+            2 => Ok(Ecc::Quartile),
+            3 => Ok(Ecc::High),
+            _ => Err(ParseEccError::OutOfRange(n)),
+        }
+    }
+}
+
+impl From<Ecc> for u8 {
+    #[inline]
+    fn from(ecc: Ecc) -> Self {
+        ecc as u8
+    }
+}
+
+impl Display for Ecc {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Ecc::Low => f.write_str("Low"),
+            Ecc::Medium => f.write_str("Medium"),
+            Ecc::Quartile => f.write_str("Quartile"),
+            Ecc::High => f.write_str("High"),
+        }
+    }
+}
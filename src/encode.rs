@@ -0,0 +1,67 @@
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{classify, trace, IntoQrPayload, Mask, QRCodeError, Version};
+
+#[inline]
+pub(crate) fn generate_qrcode<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+) -> Result<QrCode, QRCodeError> {
+    let data = data.into_qr_payload();
+    let data = data.as_ref();
+
+    trace::encode(data.len(), ecc, || QrCode::encode_segments(&classify::make_segments(data), ecc))
+}
+
+#[inline]
+pub(crate) fn generate_qrcode_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+) -> Result<QrCode, QRCodeError> {
+    let text = text.as_ref();
+
+    trace::encode(text.len(), ecc, || {
+        QrCode::encode_segments(&classify::make_segments(text.as_bytes()), ecc)
+    })
+}
+
+#[inline]
+pub(crate) fn generate_qrcode_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+) -> Result<QrCode, QRCodeError> {
+    trace::encode(segments_len(segments), ecc, || QrCode::encode_segments(segments, ecc))
+}
+
+/// The total number of characters (or bytes) of segments.
+#[inline]
+fn segments_len(segments: &[QrSegment]) -> usize {
+    segments.iter().map(|segment| segment.num_chars()).sum()
+}
+
+#[inline]
+pub(crate) fn payload_segments<D: IntoQrPayload>(data: D) -> Vec<QrSegment> {
+    let data = data.into_qr_payload();
+
+    classify::make_segments(data.as_ref())
+}
+
+/// Encode segments with a fixed version (`1` to `40`) and a fixed mask pattern (`0` to `7`). `None` means the smallest version and the best mask. The error correction level is raised as long as it does not increase the version.
+#[inline]
+pub(crate) fn generate_qrcode_advanced(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    version: Option<Version>,
+    mask: Option<Mask>,
+) -> Result<QrCode, QRCodeError> {
+    let (min_version, max_version) = match version {
+        Some(version) => (version.into(), version.into()),
+        None => (Version::MIN.into(), Version::MAX.into()),
+    };
+
+    let mask = mask.map(Into::into);
+
+    trace::encode(segments_len(segments), ecc, || {
+        QrCode::encode_segments_advanced(segments, ecc, min_version, max_version, mask, true)
+    })
+}
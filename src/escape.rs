@@ -0,0 +1,103 @@
+use std::io::{self, Write};
+
+/// Escape `&`, `<` and `>` for text content.
+#[inline]
+fn text_entity(b: u8) -> Option<&'static str> {
+    match b {
+        b'&' => Some("&amp;"),
+        b'<' => Some("&lt;"),
+        b'>' => Some("&gt;"),
+        _ => None,
+    }
+}
+
+/// Also escape `"` for double-quoted attribute values.
+#[inline]
+fn double_quoted_attribute_entity(b: u8) -> Option<&'static str> {
+    match b {
+        b'"' => Some("&quot;"),
+        _ => text_entity(b),
+    }
+}
+
+/// Also escape quotes and `/`, so that the text is safe in any context.
+#[inline]
+fn safe_entity(b: u8) -> Option<&'static str> {
+    match b {
+        b'\'' => Some("&#x27;"),
+        b'/' => Some("&#x2F;"),
+        _ => double_quoted_attribute_entity(b),
+    }
+}
+
+fn encode_to_writer<W: Write>(
+    text: &str,
+    entity: fn(u8) -> Option<&'static str>,
+    writer: &mut W,
+) -> Result<(), io::Error> {
+    let bytes = text.as_bytes();
+
+    let mut start = 0;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        if let Some(entity) = entity(b) {
+            writer.write_all(&bytes[start..i])?;
+            writer.write_all(entity.as_bytes())?;
+
+            start = i + 1;
+        }
+    }
+
+    writer.write_all(&bytes[start..])
+}
+
+fn encode_to_string(text: &str, entity: fn(u8) -> Option<&'static str>, s: &mut String) {
+    s.reserve(text.len());
+
+    let mut start = 0;
+
+    // escaped characters are ASCII, so the slices are always on character boundaries
+    for (i, b) in text.bytes().enumerate() {
+        if let Some(entity) = entity(b) {
+            s.push_str(&text[start..i]);
+            s.push_str(entity);
+
+            start = i + 1;
+        }
+    }
+
+    s.push_str(&text[start..]);
+}
+
+/// Encode text to be put in an element.
+#[inline]
+pub(crate) fn encode_text_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    writer: &mut W,
+) -> Result<(), io::Error> {
+    encode_to_writer(text.as_ref(), text_entity, writer)
+}
+
+/// Encode text to be put in a double-quoted attribute value.
+#[inline]
+pub(crate) fn encode_double_quoted_attribute_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    writer: &mut W,
+) -> Result<(), io::Error> {
+    encode_to_writer(text.as_ref(), double_quoted_attribute_entity, writer)
+}
+
+/// Encode text to be put in a double-quoted attribute value.
+#[inline]
+pub(crate) fn encode_double_quoted_attribute_to_string<S: AsRef<str>>(text: S, s: &mut String) {
+    encode_to_string(text.as_ref(), double_quoted_attribute_entity, s)
+}
+
+/// Encode text to be put in an element or an attribute value, escaping every special character.
+#[inline]
+pub(crate) fn encode_safe_to_writer<S: AsRef<str>, W: Write>(
+    text: S,
+    writer: &mut W,
+) -> Result<(), io::Error> {
+    encode_to_writer(text.as_ref(), safe_entity, writer)
+}
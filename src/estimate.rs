@@ -0,0 +1,84 @@
+use qrcodegen::{QrCodeEcc, QrSegment};
+
+use crate::{
+    classify, generate_qrcode_advanced, payload_segments, IntoQrPayload, Mask, QRCodeError,
+    QrDimensions, Version,
+};
+
+#[inline]
+fn estimate_inner(segments: &[QrSegment], ecc: QrCodeEcc) -> Result<QrDimensions, QRCodeError> {
+    // a fixed mask skips the evaluation of the mask patterns, which does not affect the dimensions
+    let qr = generate_qrcode_advanced(segments, ecc, None, Some(Mask::MIN))?;
+
+    Ok(QrDimensions {
+        version: qr.version().value(),
+        modules: qr.size() as usize,
+        ecc:     qr.error_correction_level(),
+    })
+}
+
+#[inline]
+fn highest_ecc_for_version_inner(
+    segments: &[QrSegment],
+    version: u8,
+) -> Result<QrCodeEcc, QRCodeError> {
+    let qr = generate_qrcode_advanced(
+        segments,
+        QrCodeEcc::Low,
+        Some(Version::try_from(version)?),
+        Some(Mask::MIN),
+    )?;
+
+    Ok(qr.error_correction_level())
+}
+
+/// Estimate the dimensions of the QR code of data without rendering it.
+#[inline]
+pub fn estimate<D: IntoQrPayload>(data: D, ecc: QrCodeEcc) -> Result<QrDimensions, QRCodeError> {
+    estimate_inner(&payload_segments(data), ecc)
+}
+
+/// Estimate the dimensions of the QR code of text without rendering it.
+#[inline]
+pub fn estimate_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+) -> Result<QrDimensions, QRCodeError> {
+    estimate_inner(&classify::make_segments(text.as_ref().as_bytes()), ecc)
+}
+
+/// Estimate the dimensions of the QR code of segments without rendering it.
+#[inline]
+pub fn estimate_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+) -> Result<QrDimensions, QRCodeError> {
+    estimate_inner(segments, ecc)
+}
+
+/// Find the highest error correction level with which data fits in a fixed version (`1` to `40`). Use it with `RenderOptions::version` to make QR codes of the same size.
+#[inline]
+pub fn highest_ecc_for_version<D: IntoQrPayload>(
+    data: D,
+    version: u8,
+) -> Result<QrCodeEcc, QRCodeError> {
+    highest_ecc_for_version_inner(&payload_segments(data), version)
+}
+
+/// Find the highest error correction level with which text fits in a fixed version (`1` to `40`). Use it with `RenderOptions::version` to make QR codes of the same size.
+#[inline]
+pub fn highest_ecc_for_version_from_str<S: AsRef<str>>(
+    text: S,
+    version: u8,
+) -> Result<QrCodeEcc, QRCodeError> {
+    highest_ecc_for_version_inner(&classify::make_segments(text.as_ref().as_bytes()), version)
+}
+
+/// Find the highest error correction level with which segments fit in a fixed version (`1` to `40`). Use it with `RenderOptions::version` to make QR codes of the same size.
+#[inline]
+pub fn highest_ecc_for_version_from_segments(
+    segments: &[QrSegment],
+    version: u8,
+) -> Result<QrCodeEcc, QRCodeError> {
+    highest_ecc_for_version_inner(segments, version)
+}
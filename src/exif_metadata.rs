@@ -0,0 +1,319 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{QRCodeError, Validate};
+
+const TYPE_BYTE: u16 = 1;
+const TYPE_ASCII: u16 = 2;
+const TYPE_LONG: u16 = 4;
+const TYPE_RATIONAL: u16 = 5;
+const TYPE_UNDEFINED: u16 = 7;
+
+/// Basic EXIF fields written to JPEG images, so that asset-management systems can index them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExifMetadata {
+    pub(crate) software:    Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) date_time:   Option<SystemTime>,
+    pub(crate) gps:         Option<(f64, f64)>,
+}
+
+impl ExifMetadata {
+    /// Create metadata whose software is this crate and whose other fields are unset.
+    #[inline]
+    pub fn new() -> Self {
+        ExifMetadata {
+            software:    Some(format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))),
+            description: None,
+            date_time:   None,
+            gps:         None,
+        }
+    }
+
+    /// Set the name of the software which created the image. The default value is `qrcode-generator <version>`.
+    #[inline]
+    pub fn software(mut self, software: Option<String>) -> Self {
+        self.software = software;
+
+        self
+    }
+
+    /// Set the description of the image, e.g. the payload or what it is for.
+    #[inline]
+    pub fn description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+
+        self
+    }
+
+    /// Set the creation time of the image. It is written in UTC.
+    #[inline]
+    pub fn date_time(mut self, date_time: Option<SystemTime>) -> Self {
+        self.date_time = date_time;
+
+        self
+    }
+
+    /// Set the location of the image as the latitude and the longitude in degrees.
+    #[inline]
+    pub fn gps(mut self, gps: Option<(f64, f64)>) -> Self {
+        self.gps = gps;
+
+        self
+    }
+
+    /// Set the location of the image from a geo URI payload, e.g. `geo:25.0330,121.5654`.
+    pub fn gps_from_geo_uri<S: AsRef<str>>(self, uri: S) -> Result<Self, QRCodeError> {
+        let uri = uri.as_ref();
+
+        let coordinates = uri
+            .get(..4)
+            .filter(|scheme| scheme.eq_ignore_ascii_case("geo:"))
+            .map(|_| &uri[4..])
+            .ok_or_else(|| QRCodeError::invalid_input("geo_uri", "must start with `geo:`"))?;
+
+        let coordinates = coordinates.split(['?', ';']).next().unwrap_or_default();
+
+        let mut numbers = coordinates.split(',').map(|s| s.trim().parse::<f64>());
+
+        match (numbers.next(), numbers.next()) {
+            (Some(Ok(latitude)), Some(Ok(longitude))) => Ok(self.gps(Some((latitude, longitude)))),
+            _ => Err(QRCodeError::invalid_input(
+                "geo_uri",
+                "must have a latitude and a longitude separated by a comma",
+            )),
+        }
+    }
+
+    /// Serialize the fields to a little-endian TIFF structure, the payload of the APP1 segment after the `Exif` header.
+    pub(crate) fn to_tiff(&self) -> Vec<u8> {
+        let date_time = self.date_time.map(format_date_time);
+
+        let mut ifd0 = Vec::new();
+
+        if let Some(description) = &self.description {
+            ifd0.push(Entry::ascii(0x010E, description));
+        }
+
+        if let Some(software) = &self.software {
+            ifd0.push(Entry::ascii(0x0131, software));
+        }
+
+        if let Some(date_time) = &date_time {
+            ifd0.push(Entry::ascii(0x0132, date_time));
+        }
+
+        let mut exif_ifd = Vec::new();
+
+        if let Some(date_time) = &date_time {
+            exif_ifd.push(Entry::new(0x9000, TYPE_UNDEFINED, 4, b"0232".to_vec()));
+            exif_ifd.push(Entry::ascii(0x9003, date_time));
+            exif_ifd.push(Entry::ascii(0x9010, "+00:00"));
+            exif_ifd.push(Entry::ascii(0x9011, "+00:00"));
+        }
+
+        let mut gps_ifd = Vec::new();
+
+        if let Some((latitude, longitude)) = self.gps {
+            gps_ifd.push(Entry::new(0x0000, TYPE_BYTE, 4, vec![2, 3, 0, 0]));
+            gps_ifd.push(Entry::ascii(0x0001, if latitude < 0.0 { "S" } else { "N" }));
+            gps_ifd.push(Entry::degrees(0x0002, latitude));
+            gps_ifd.push(Entry::ascii(0x0003, if longitude < 0.0 { "W" } else { "E" }));
+            gps_ifd.push(Entry::degrees(0x0004, longitude));
+        }
+
+        // the sub-IFDs follow IFD0 in order, so their offsets are known before writing
+        let mut offset = 8 + ifd_size(&ifd0) + pointer_count(&exif_ifd, &gps_ifd) * 12;
+
+        if !exif_ifd.is_empty() {
+            ifd0.push(Entry::long(0x8769, offset as u32));
+
+            offset += ifd_size(&exif_ifd);
+        }
+
+        if !gps_ifd.is_empty() {
+            ifd0.push(Entry::long(0x8825, offset as u32));
+        }
+
+        let mut tiff = Vec::with_capacity(256);
+
+        tiff.extend_from_slice(b"II\x2A\x00\x08\x00\x00\x00");
+
+        for ifd in [ifd0, exif_ifd, gps_ifd] {
+            if !ifd.is_empty() {
+                write_ifd(&mut tiff, &ifd);
+            }
+        }
+
+        tiff
+    }
+}
+
+impl Default for ExifMetadata {
+    #[inline]
+    fn default() -> Self {
+        ExifMetadata::new()
+    }
+}
+
+impl Validate for ExifMetadata {
+    fn validate(&self) -> Result<(), QRCodeError> {
+        for (field, text) in [("software", &self.software), ("description", &self.description)] {
+            if text.as_deref().map_or(false, |text| text.contains('\0')) {
+                return Err(QRCodeError::invalid_input(field, "must not contain NUL"));
+            }
+        }
+
+        if let Some(date_time) = self.date_time {
+            if date_time < UNIX_EPOCH {
+                return Err(QRCodeError::invalid_input("date_time", "must not be before 1970"));
+            }
+        }
+
+        if let Some((latitude, longitude)) = self.gps {
+            if !(-90.0..=90.0).contains(&latitude) {
+                return Err(QRCodeError::invalid_input(
+                    "gps",
+                    "the latitude must be between -90 and 90",
+                ));
+            }
+
+            if !(-180.0..=180.0).contains(&longitude) {
+                return Err(QRCodeError::invalid_input(
+                    "gps",
+                    "the longitude must be between -180 and 180",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct Entry {
+    tag:        u16,
+    field_type: u16,
+    count:      u32,
+    value:      Vec<u8>,
+}
+
+impl Entry {
+    #[inline]
+    fn new(tag: u16, field_type: u16, count: u32, value: Vec<u8>) -> Self {
+        Entry {
+            tag,
+            field_type,
+            count,
+            value,
+        }
+    }
+
+    fn ascii(tag: u16, text: &str) -> Self {
+        let mut value = Vec::with_capacity(text.len() + 1);
+
+        value.extend_from_slice(text.as_bytes());
+        value.push(0);
+
+        Entry::new(tag, TYPE_ASCII, value.len() as u32, value)
+    }
+
+    #[inline]
+    fn long(tag: u16, n: u32) -> Self {
+        Entry::new(tag, TYPE_LONG, 1, n.to_le_bytes().to_vec())
+    }
+
+    /// Degrees, minutes and seconds (to a thousandth) of an absolute coordinate.
+    fn degrees(tag: u16, coordinate: f64) -> Self {
+        let milliseconds = (coordinate.abs() * 3_600_000.0).round() as u64;
+
+        let mut value = Vec::with_capacity(24);
+
+        for (numerator, denominator) in [
+            (milliseconds / 3_600_000, 1),
+            (milliseconds / 60_000 % 60, 1),
+            (milliseconds % 60_000, 1000),
+        ] {
+            value.extend_from_slice(&(numerator as u32).to_le_bytes());
+            value.extend_from_slice(&(denominator as u32).to_le_bytes());
+        }
+
+        Entry::new(tag, TYPE_RATIONAL, 3, value)
+    }
+}
+
+#[inline]
+fn pointer_count(exif_ifd: &[Entry], gps_ifd: &[Entry]) -> usize {
+    usize::from(!exif_ifd.is_empty()) + usize::from(!gps_ifd.is_empty())
+}
+
+/// The size of an IFD with the values which do not fit in its entries.
+fn ifd_size(entries: &[Entry]) -> usize {
+    let values: usize = entries
+        .iter()
+        .filter(|entry| entry.value.len() > 4)
+        .map(|entry| (entry.value.len() + 1) & !1)
+        .sum();
+
+    2 + entries.len() * 12 + 4 + values
+}
+
+fn write_ifd(tiff: &mut Vec<u8>, entries: &[Entry]) {
+    let mut value_offset = tiff.len() + 2 + entries.len() * 12 + 4;
+
+    let mut values = Vec::new();
+
+    tiff.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+
+    for entry in entries {
+        tiff.extend_from_slice(&entry.tag.to_le_bytes());
+        tiff.extend_from_slice(&entry.field_type.to_le_bytes());
+        tiff.extend_from_slice(&entry.count.to_le_bytes());
+
+        if entry.value.len() > 4 {
+            tiff.extend_from_slice(&(value_offset as u32).to_le_bytes());
+
+            values.extend_from_slice(&entry.value);
+
+            // values start on word boundaries
+            if entry.value.len() % 2 == 1 {
+                values.push(0);
+            }
+
+            value_offset += (entry.value.len() + 1) & !1;
+        } else {
+            let mut inline = [0u8; 4];
+
+            inline[..entry.value.len()].copy_from_slice(&entry.value);
+
+            tiff.extend_from_slice(&inline);
+        }
+    }
+
+    // no next IFD
+    tiff.extend_from_slice(&[0; 4]);
+    tiff.extend_from_slice(&values);
+}
+
+/// Format a time as `YYYY:MM:DD HH:MM:SS` in UTC.
+fn format_date_time(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+
+    // the civil-from-days algorithm of Howard Hinnant
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}:{month:02}:{day:02} {:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
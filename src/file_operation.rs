@@ -0,0 +1,23 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The step at which writing an image file failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileOperation {
+    /// Creating the file or its parent directories.
+    Create,
+    /// Writing the image data to the file.
+    Write,
+    /// Writing the image data to the file failed, and the partially written file could not be removed either. The source is the error of writing.
+    Cleanup,
+}
+
+impl Display for FileOperation {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            FileOperation::Create => f.write_str("create"),
+            FileOperation::Write => f.write_str("write"),
+            FileOperation::Cleanup => f.write_str("clean up"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+#[cfg(feature = "image")]
+use image::ImageError;
+
+use crate::{FileOperation, QRCodeError};
+
+/// Options for the `to_*_to_file_with_options` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileOptions {
+    overwrite:   bool,
+    create_dirs: bool,
+}
+
+impl FileOptions {
+    /// Create the default options, which overwrite the existing file and do not create missing parent directories.
+    #[inline]
+    pub const fn new() -> Self {
+        FileOptions {
+            overwrite: true, create_dirs: false
+        }
+    }
+
+    /// Set whether to overwrite the existing file. If it is `false`, the output fails with an `AlreadyExists` IO error when the destination exists, and the existing file is kept untouched.
+    #[inline]
+    pub const fn overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+
+        self
+    }
+
+    /// Set whether to create the missing parent directories of the destination, like `fs::create_dir_all`.
+    #[inline]
+    pub const fn create_dirs(mut self, create_dirs: bool) -> Self {
+        self.create_dirs = create_dirs;
+
+        self
+    }
+}
+
+impl Default for FileOptions {
+    #[inline]
+    fn default() -> Self {
+        FileOptions::new()
+    }
+}
+
+impl FileOptions {
+    pub(crate) fn create<P: AsRef<Path>>(&self, path: P) -> Result<File, io::Error> {
+        let path = path.as_ref();
+
+        if self.create_dirs {
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent)?;
+                }
+            }
+        }
+
+        let mut options = OpenOptions::new();
+
+        options.write(true);
+
+        if self.overwrite {
+            options.create(true).truncate(true);
+        } else {
+            options.create_new(true);
+        }
+
+        options.open(path)
+    }
+}
+
+impl FileOptions {
+    /// Create the file, write it through a buffer, and remove it if writing fails. IO errors are tagged with the path and the failed operation. Other errors are returned as they are.
+    pub(crate) fn write<
+        P: AsRef<Path>,
+        F: FnOnce(&mut BufWriter<File>) -> Result<(), QRCodeError>,
+    >(
+        &self,
+        path: P,
+        f: F,
+    ) -> Result<(), QRCodeError> {
+        let path = path.as_ref();
+
+        let file = self
+            .create(path)
+            .map_err(|err| QRCodeError::file_error(path, FileOperation::Create, err))?;
+
+        let mut writer = BufWriter::new(file);
+
+        let result = f(&mut writer).and_then(|_| Ok(writer.flush()?));
+
+        drop(writer);
+
+        if let Err(err) = result {
+            // a failure to remove the file is reported without hiding the error which caused the removal
+            let operation = match fs::remove_file(path) {
+                Ok(()) => FileOperation::Write,
+                Err(_) => FileOperation::Cleanup,
+            };
+
+            return Err(match err {
+                QRCodeError::IOError(err) => QRCodeError::file_error(path, operation, err),
+                #[cfg(feature = "image")]
+                QRCodeError::ImageError(ImageError::IoError(err)) => {
+                    QRCodeError::file_error(path, operation, err)
+                },
+                _ => err,
+            });
+        }
+
+        Ok(())
+    }
+}
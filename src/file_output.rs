@@ -0,0 +1,53 @@
+use std::{io::Write, path::Path};
+
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str,
+    render_options::DEFAULT_RENDER_OPTIONS, render_to_vec_inner, FileOptions, IntoQrPayload,
+    OutputFormat, OutputSpec, QRCodeError,
+};
+
+#[inline]
+fn to_file_inner<P: AsRef<Path>>(qr: QrCode, size: usize, path: P) -> Result<(), QRCodeError> {
+    let path = path.as_ref();
+
+    let format = OutputFormat::from_path(path).ok_or(QRCodeError::UnknownFormat)?;
+
+    let data = render_to_vec_inner(&qr, &DEFAULT_RENDER_OPTIONS, OutputSpec::new(format, size))?;
+
+    FileOptions::new().write(path, |file| Ok(file.write_all(&data)?))
+}
+
+/// Encode data to an image via a file path. The format is detected from the file extension, see `OutputFormat::from_extension`.
+#[inline]
+pub fn to_file<D: IntoQrPayload, P: AsRef<Path>>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_file_inner(generate_qrcode(data, ecc)?, size, path)
+}
+
+/// Encode text to an image via a file path. The format is detected from the file extension, see `OutputFormat::from_extension`.
+#[inline]
+pub fn to_file_from_str<S: AsRef<str>, P: AsRef<Path>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_file_inner(generate_qrcode_from_str(text, ecc)?, size, path)
+}
+
+/// Encode segments to an image via a file path. The format is detected from the file extension, see `OutputFormat::from_extension`.
+#[inline]
+pub fn to_file_from_segments<P: AsRef<Path>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_file_inner(generate_qrcode_from_segments(segments, ecc)?, size, path)
+}
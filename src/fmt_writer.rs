@@ -0,0 +1,35 @@
+use std::{
+    fmt,
+    io::{self, ErrorKind, Write},
+    str::from_utf8,
+};
+
+/// An adapter which lets the renderers, written against `io::Write`, write to a `fmt::Write`. The renderers only write whole strings or slices split at ASCII characters, so every write is valid UTF-8.
+pub(crate) struct FmtWriter<'a, W: fmt::Write> {
+    inner: &'a mut W,
+}
+
+impl<'a, W: fmt::Write> FmtWriter<'a, W> {
+    #[inline]
+    pub(crate) fn new(inner: &'a mut W) -> Self {
+        FmtWriter {
+            inner,
+        }
+    }
+}
+
+impl<W: fmt::Write> Write for FmtWriter<'_, W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        let s = from_utf8(buf).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        self.inner.write_str(s).map_err(|err| io::Error::new(ErrorKind::Other, err))?;
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        Ok(())
+    }
+}
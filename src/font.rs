@@ -0,0 +1,33 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+use ab_glyph::FontVec;
+
+use crate::QRCodeError;
+
+/// A TrueType or OpenType font used to draw text in raster images.
+#[derive(Clone)]
+pub struct Font {
+    pub(crate) font: Arc<FontVec>,
+}
+
+impl Font {
+    /// Load a font from the data of a font file.
+    #[inline]
+    pub fn try_from_vec(data: Vec<u8>) -> Result<Self, QRCodeError> {
+        let font = FontVec::try_from_vec(data).map_err(|_| QRCodeError::InvalidFont)?;
+
+        Ok(Font {
+            font: Arc::new(font)
+        })
+    }
+}
+
+impl Debug for Font {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("Font").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,157 @@
+use sha2::{Digest, Sha256};
+
+/// The xoshiro256** generator seeded by the SHA-256 digest of the seed, as specified by Blockchain Commons.
+pub(crate) struct Xoshiro256 {
+    s: [u64; 4],
+}
+
+impl Xoshiro256 {
+    pub(crate) fn new(seed: &[u8]) -> Self {
+        let digest = Sha256::digest(seed);
+
+        let mut s = [0u64; 4];
+
+        for (v, chunk) in s.iter_mut().zip(digest.chunks_exact(8)) {
+            let mut bytes = [0u8; 8];
+
+            bytes.copy_from_slice(chunk);
+
+            *v = u64::from_be_bytes(bytes);
+        }
+
+        Xoshiro256 {
+            s,
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    /// Generate a number in the range `[0, 1)`.
+    #[inline]
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        self.next_u64() as f64 / (u64::MAX as f64 + 1.0)
+    }
+
+    /// Generate an integer in the range `[low, high]`.
+    #[inline]
+    pub(crate) fn next_int(&mut self, low: usize, high: usize) -> usize {
+        (self.next_f64() * (high - low + 1) as f64) as usize + low
+    }
+}
+
+/// Pick a random index weighted by probabilities, using Vose's alias method.
+struct RandomSampler {
+    probs:   Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl RandomSampler {
+    fn new(probs: &[f64]) -> Self {
+        let n = probs.len();
+
+        let sum: f64 = probs.iter().sum();
+
+        let mut p: Vec<f64> = probs.iter().map(|&v| v * n as f64 / sum).collect();
+
+        let mut small = Vec::with_capacity(n);
+        let mut large = Vec::with_capacity(n);
+
+        // the index order is reversed to match the reference implementation
+        for i in (0..n).rev() {
+            if p[i] < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        let mut sampler_probs = vec![0.0; n];
+        let mut aliases = vec![0; n];
+
+        while let (Some(&a), Some(&g)) = (small.last(), large.last()) {
+            small.pop();
+            large.pop();
+
+            sampler_probs[a] = p[a];
+            aliases[a] = g;
+
+            p[g] += p[a] - 1.0;
+
+            if p[g] < 1.0 {
+                small.push(g);
+            } else {
+                large.push(g);
+            }
+        }
+
+        // the remaining ones can only be left by numeric instability
+        for i in large.into_iter().chain(small) {
+            sampler_probs[i] = 1.0;
+        }
+
+        RandomSampler {
+            probs: sampler_probs,
+            aliases,
+        }
+    }
+
+    fn next(&self, rng: &mut Xoshiro256) -> usize {
+        let r1 = rng.next_f64();
+        let r2 = rng.next_f64();
+
+        let i = (self.probs.len() as f64 * r1) as usize;
+
+        if r2 < self.probs[i] {
+            i
+        } else {
+            self.aliases[i]
+        }
+    }
+}
+
+/// Choose the indexes of the fragments mixed into the part `seq_num` (starting from 1).
+pub(crate) fn choose_fragments(seq_num: u32, seq_len: usize, checksum: u32) -> Vec<usize> {
+    // the first `seq_len` parts are the pure fragments
+    if seq_num as usize <= seq_len {
+        return vec![seq_num as usize - 1];
+    }
+
+    let mut seed = [0u8; 8];
+
+    seed[..4].copy_from_slice(&seq_num.to_be_bytes());
+    seed[4..].copy_from_slice(&checksum.to_be_bytes());
+
+    let mut rng = Xoshiro256::new(&seed);
+
+    let degree_probs: Vec<f64> = (1..=seq_len).map(|i| 1.0 / i as f64).collect();
+
+    let degree = RandomSampler::new(&degree_probs).next(&mut rng) + 1;
+
+    let mut remaining: Vec<usize> = (0..seq_len).collect();
+    let mut shuffled = Vec::with_capacity(seq_len);
+
+    while !remaining.is_empty() {
+        let index = rng.next_int(0, remaining.len() - 1);
+
+        shuffled.push(remaining.remove(index));
+    }
+
+    shuffled.truncate(degree);
+
+    shuffled
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+#[cfg(any(feature = "image", feature = "tiff"))]
+use crate::QRCodeError;
+
+/// An ICC color profile which can be embedded in images.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IccProfile {
+    data: Arc<[u8]>,
+}
+
+impl IccProfile {
+    /// Use a caller-supplied ICC profile. The data is embedded as it is.
+    #[inline]
+    pub fn new<D: Into<Arc<[u8]>>>(data: D) -> Self {
+        IccProfile {
+            data: data.into()
+        }
+    }
+
+    /// Create a compact ICC v4 sRGB display profile.
+    pub fn srgb() -> Self {
+        let mut tags: Vec<(&[u8; 4], Vec<u8>)> = Vec::with_capacity(10);
+
+        tags.push((b"desc", mluc("sRGB")));
+        tags.push((b"cprt", mluc("No copyright, use freely")));
+        tags.push((b"wtpt", xyz(&[0.9642, 1.0, 0.8249])));
+        tags.push((b"rXYZ", xyz(&[0.436066, 0.222488, 0.013916])));
+        tags.push((b"gXYZ", xyz(&[0.385147, 0.716873, 0.097076])));
+        tags.push((b"bXYZ", xyz(&[0.143066, 0.060608, 0.714096])));
+        tags.push((
+            b"chad",
+            sf32(&[
+                1.047882, 0.022918, -0.050217, 0.029586, 0.990478, -0.017075, -0.009247, 0.015075,
+                0.751678,
+            ]),
+        ));
+
+        let trc = para_srgb();
+
+        tags.push((b"rTRC", trc.clone()));
+        tags.push((b"gTRC", trc.clone()));
+        tags.push((b"bTRC", trc));
+
+        let tag_table_length = 4 + 12 * tags.len();
+
+        let mut offset = 128 + tag_table_length;
+
+        let mut tag_table = Vec::with_capacity(tag_table_length);
+        let mut tag_data = Vec::new();
+
+        tag_table.extend_from_slice(&(tags.len() as u32).to_be_bytes());
+
+        for (signature, data) in tags.iter() {
+            tag_table.extend_from_slice(*signature);
+            tag_table.extend_from_slice(&(offset as u32).to_be_bytes());
+            tag_table.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+            tag_data.extend_from_slice(data);
+
+            // every tag starts on a 4-byte boundary
+            while tag_data.len() % 4 != 0 {
+                tag_data.push(0);
+            }
+
+            offset = 128 + tag_table_length + tag_data.len();
+        }
+
+        let profile_size = offset;
+
+        let mut profile = Vec::with_capacity(profile_size);
+
+        profile.extend_from_slice(&(profile_size as u32).to_be_bytes());
+        profile.extend_from_slice(&[0; 4]); // preferred CMM type
+        profile.extend_from_slice(&[4, 0x30, 0, 0]); // version 4.3
+        profile.extend_from_slice(b"mntr");
+        profile.extend_from_slice(b"RGB ");
+        profile.extend_from_slice(b"XYZ ");
+        profile.extend_from_slice(&[0x07, 0xE8, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0]); // 2024-01-01 00:00:00
+        profile.extend_from_slice(b"acsp");
+        profile.extend_from_slice(&[0; 24]); // platform, flags, manufacturer, model, attributes
+        profile.extend_from_slice(&[0; 4]); // perceptual rendering intent
+        profile.extend_from_slice(&xyz(&[0.9642, 1.0, 0.8249])[8..]); // D50
+        profile.extend_from_slice(&[0; 4]); // creator
+        profile.extend_from_slice(&[0; 16]); // profile ID
+        profile.extend_from_slice(&[0; 28]); // reserved
+
+        profile.extend_from_slice(&tag_table);
+        profile.extend_from_slice(&tag_data);
+
+        IccProfile::new(profile)
+    }
+
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the color space signature in the profile header, e.g. `b"RGB "`, `b"GRAY"` or `b"CMYK"`. `None` means the data is too short to be a profile.
+    #[inline]
+    pub fn color_space(&self) -> Option<[u8; 4]> {
+        self.data.get(16..20).and_then(|signature| signature.try_into().ok())
+    }
+
+    /// Make sure the profile describes one of the color spaces of an output, which embeds it in `format`.
+    #[cfg(any(feature = "image", feature = "tiff"))]
+    pub(crate) fn check_color_space(
+        &self,
+        color_spaces: &[&[u8; 4]],
+        format: &str,
+    ) -> Result<[u8; 4], QRCodeError> {
+        match self.color_space() {
+            Some(color_space) if color_spaces.contains(&&color_space) => Ok(color_space),
+            Some(color_space) => Err(QRCodeError::invalid_input(
+                "icc_profile",
+                format!(
+                    "the {:?} color space cannot be embedded in {format} images",
+                    String::from_utf8_lossy(&color_space).trim_end()
+                ),
+            )),
+            None => Err(QRCodeError::invalid_input("icc_profile", "the profile is truncated")),
+        }
+    }
+}
+
+#[inline]
+fn s15_fixed16(v: f64) -> [u8; 4] {
+    ((v * 65536.0).round() as i32).to_be_bytes()
+}
+
+fn xyz(values: &[f64; 3]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(20);
+
+    data.extend_from_slice(b"XYZ ");
+    data.extend_from_slice(&[0; 4]);
+
+    for v in values.iter().copied() {
+        data.extend_from_slice(&s15_fixed16(v));
+    }
+
+    data
+}
+
+fn sf32(values: &[f64; 9]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(44);
+
+    data.extend_from_slice(b"sf32");
+    data.extend_from_slice(&[0; 4]);
+
+    for v in values.iter().copied() {
+        data.extend_from_slice(&s15_fixed16(v));
+    }
+
+    data
+}
+
+fn para_srgb() -> Vec<u8> {
+    let mut data = Vec::with_capacity(32);
+
+    data.extend_from_slice(b"para");
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&[0, 3, 0, 0]); // Y = (aX + b)^g if X >= d, else Y = cX
+
+    for v in [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045] {
+        data.extend_from_slice(&s15_fixed16(v));
+    }
+
+    data
+}
+
+fn mluc(text: &str) -> Vec<u8> {
+    let text: Vec<u8> = text.encode_utf16().flat_map(|c| c.to_be_bytes()).collect();
+
+    let mut data = Vec::with_capacity(28 + text.len());
+
+    data.extend_from_slice(b"mluc");
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&1u32.to_be_bytes()); // number of records
+    data.extend_from_slice(&12u32.to_be_bytes()); // record size
+    data.extend_from_slice(b"enUS");
+    data.extend_from_slice(&(text.len() as u32).to_be_bytes());
+    data.extend_from_slice(&28u32.to_be_bytes());
+    data.extend_from_slice(&text);
+
+    data
+}
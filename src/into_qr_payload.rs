@@ -0,0 +1,137 @@
+use std::{
+    borrow::Cow,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    rc::Rc,
+    sync::Arc,
+};
+
+/// Types which can be converted to the data stored in a QR code.
+///
+/// Text is encoded as it is. Every reference to a type implementing `AsRef<[u8]>` is accepted, so `&str`, `&[u8]` and custom byte containers such as `&bytes::Bytes` can be passed. Wrap a custom byte container in `QrBytes` to pass it by value. Other values are formatted to their canonical text representations, e.g. `IpAddr` becomes `"127.0.0.1"`. They are taken by value, because implementations for references to them would overlap with the one for references to bytes, so pass `url.as_str()` to borrow a `url::Url`.
+pub trait IntoQrPayload {
+    type Payload: AsRef<[u8]>;
+
+    /// Convert to the data stored in a QR code.
+    fn into_qr_payload(self) -> Self::Payload;
+}
+
+macro_rules! impl_identity {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl IntoQrPayload for $t {
+                type Payload = Self;
+
+                #[inline]
+                fn into_qr_payload(self) -> Self::Payload {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_identity!(String, Vec<u8>, Box<[u8]>, Rc<[u8]>, Arc<[u8]>);
+
+impl<'a> IntoQrPayload for Cow<'a, [u8]> {
+    type Payload = Self;
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        self
+    }
+}
+
+/// Owned bytes of any type implementing `AsRef<[u8]>`, such as `bytes::Bytes`, passed by value as data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct QrBytes<T>(pub T);
+
+impl<T: AsRef<[u8]>> IntoQrPayload for QrBytes<T> {
+    type Payload = T;
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        self.0
+    }
+}
+
+/// Any borrowed bytes, such as `&str`, `&[u8]`, `&Vec<u8>`, `&Cow<[u8]>` or `&bytes::Bytes`.
+impl<'a, T: AsRef<[u8]> + ?Sized> IntoQrPayload for &'a T {
+    type Payload = &'a [u8];
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        self.as_ref()
+    }
+}
+
+impl<const N: usize> IntoQrPayload for [u8; N] {
+    type Payload = Self;
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        self
+    }
+}
+
+impl IntoQrPayload for Box<str> {
+    type Payload = Box<[u8]>;
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        self.into_boxed_bytes()
+    }
+}
+
+impl<'a> IntoQrPayload for Cow<'a, str> {
+    type Payload = Cow<'a, [u8]>;
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        match self {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        }
+    }
+}
+
+macro_rules! impl_to_string {
+    ($($t:ty),* $(,)*) => {
+        $(
+            impl IntoQrPayload for $t {
+                type Payload = String;
+
+                #[inline]
+                fn into_qr_payload(self) -> Self::Payload {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_string!(IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr);
+
+#[cfg(feature = "url")]
+impl IntoQrPayload for url::Url {
+    type Payload = String;
+
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        self.into()
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl IntoQrPayload for uuid::Uuid {
+    type Payload = [u8; uuid::fmt::Hyphenated::LENGTH];
+
+    /// Format to the lowercase hyphenated form, e.g. `"67e55044-10b1-426f-9247-bb680e5fe0c8"`.
+    #[inline]
+    fn into_qr_payload(self) -> Self::Payload {
+        let mut buffer = [0u8; uuid::fmt::Hyphenated::LENGTH];
+
+        self.hyphenated().encode_lower(&mut buffer);
+
+        buffer
+    }
+}
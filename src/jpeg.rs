@@ -0,0 +1,171 @@
+use std::io::Write;
+
+use image::{codecs::jpeg::JpegEncoder, ExtendedColorType, ImageEncoder, ImageError, ImageFormat};
+use qrcodegen::QrCode;
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str, raster::to_rgba,
+    to_image_inner, ExifMetadata, IntoQrPayload, QRCodeError, QrCodeEcc, QrSegment, RenderOptions,
+    Validate,
+};
+
+/// The quality of JPEG images rendered as `OutputFormat::Jpeg`.
+pub(crate) const RENDER_QUALITY: u8 = 90;
+
+/// The bytes of buffers of a pixel, four bytes of RGBA8 pixels and three bytes of their RGB8 copy.
+const BYTES_PER_PIXEL: u64 = 7;
+
+/// Render a QR code to a JPEG image with the colors and the ICC profile in the options. JPEG images have no alpha channel, so translucent pixels are composited over white.
+pub(crate) fn to_jpeg_render_inner<W: Write>(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    let rgba = to_rgba(qr, size, options, BYTES_PER_PIXEL)?;
+
+    let rgb: Vec<u8> = rgba
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let alpha = u16::from(pixel[3]);
+
+            // round(c × a / 255 + 255 × (1 - a / 255))
+            [0, 1, 2]
+                .map(|i| ((u16::from(pixel[i]) * alpha + 255 * (255 - alpha) + 127) / 255) as u8)
+        })
+        .collect();
+
+    drop(rgba);
+
+    let mut encoder = JpegEncoder::new_with_quality(writer, RENDER_QUALITY);
+
+    if let Some(icc_profile) = options.icc_profile.as_ref() {
+        icc_profile.check_color_space(&[b"RGB "], "JPEG")?;
+
+        encoder
+            .set_icc_profile(icc_profile.as_bytes().to_vec())
+            .map_err(ImageError::Unsupported)?;
+    }
+
+    Ok(encoder.write_image(&rgb, size as u32, size as u32, ExtendedColorType::Rgb8)?)
+}
+
+fn to_jpeg_inner<W: Write>(
+    qr: QrCode,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    if !(1..=100).contains(&quality) {
+        return Err(QRCodeError::invalid_input("quality", "must be between 1 and 100"));
+    }
+
+    metadata.validate()?;
+
+    let img_raw = to_image_inner(qr, size)?;
+
+    let mut encoder = JpegEncoder::new_with_quality(writer, quality);
+
+    encoder.set_exif_metadata(metadata.to_tiff()).map_err(|err| {
+        ImageError::Unsupported(image::error::UnsupportedError::from_format_and_kind(
+            ImageFormat::Jpeg.into(),
+            err.kind(),
+        ))
+    })?;
+
+    Ok(encoder.write_image(&img_raw, size as u32, size as u32, ExtendedColorType::L8)?)
+}
+
+/// Encode data to a JPEG image with EXIF metadata in memory. `quality` is between 1 and 100.
+#[inline]
+pub fn to_jpeg_to_vec<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+) -> Result<Vec<u8>, QRCodeError> {
+    let mut jpeg = Vec::with_capacity(8192);
+
+    to_jpeg_inner(generate_qrcode(data, ecc)?, size, quality, metadata, &mut jpeg)?;
+
+    Ok(jpeg)
+}
+
+/// Encode text to a JPEG image with EXIF metadata in memory. `quality` is between 1 and 100.
+#[inline]
+pub fn to_jpeg_to_vec_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+) -> Result<Vec<u8>, QRCodeError> {
+    let mut jpeg = Vec::with_capacity(8192);
+
+    to_jpeg_inner(generate_qrcode_from_str(text, ecc)?, size, quality, metadata, &mut jpeg)?;
+
+    Ok(jpeg)
+}
+
+/// Encode segments to a JPEG image with EXIF metadata in memory. `quality` is between 1 and 100.
+#[inline]
+pub fn to_jpeg_to_vec_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+) -> Result<Vec<u8>, QRCodeError> {
+    let mut jpeg = Vec::with_capacity(8192);
+
+    to_jpeg_inner(
+        generate_qrcode_from_segments(segments, ecc)?,
+        size,
+        quality,
+        metadata,
+        &mut jpeg,
+    )?;
+
+    Ok(jpeg)
+}
+
+/// Encode data to a JPEG image with EXIF metadata via a writer. `quality` is between 1 and 100.
+#[inline]
+pub fn to_jpeg_to_writer<D: IntoQrPayload, W: Write>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_jpeg_inner(generate_qrcode(data, ecc)?, size, quality, metadata, writer)
+}
+
+/// Encode text to a JPEG image with EXIF metadata via a writer. `quality` is between 1 and 100.
+#[inline]
+pub fn to_jpeg_to_writer_from_str<S: AsRef<str>, W: Write>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_jpeg_inner(generate_qrcode_from_str(text, ecc)?, size, quality, metadata, writer)
+}
+
+/// Encode segments to a JPEG image with EXIF metadata via a writer. `quality` is between 1 and 100.
+#[inline]
+pub fn to_jpeg_to_writer_from_segments<W: Write>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    quality: u8,
+    metadata: &ExifMetadata,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_jpeg_inner(generate_qrcode_from_segments(segments, ecc)?, size, quality, metadata, writer)
+}
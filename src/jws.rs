@@ -0,0 +1,97 @@
+use p256::ecdsa::{signature::Signer, Signature};
+
+use crate::{base64, estimate_from_str, QRCodeError, QrCodeEcc, QrDimensions, RenderWarning};
+
+/// A key for signing JWS payloads.
+#[derive(Debug, Clone)]
+pub enum JwsKey {
+    /// ECDSA with P-256 and SHA-256 (`ES256`).
+    Es256(p256::ecdsa::SigningKey),
+    /// Ed25519 (`EdDSA`).
+    EdDsa(ed25519_dalek::SigningKey),
+}
+
+impl JwsKey {
+    /// Create an `ES256` key from a 32-byte big-endian secret scalar.
+    #[inline]
+    pub fn es256_from_bytes(secret: &[u8]) -> Result<Self, QRCodeError> {
+        p256::ecdsa::SigningKey::from_slice(secret)
+            .map(JwsKey::Es256)
+            .map_err(|_| QRCodeError::invalid_input("secret", "not a valid P-256 secret key"))
+    }
+
+    /// Create an `EdDSA` key from a 32-byte Ed25519 seed.
+    #[inline]
+    pub fn ed25519_from_bytes(secret: &[u8; 32]) -> Self {
+        JwsKey::EdDsa(ed25519_dalek::SigningKey::from_bytes(secret))
+    }
+
+    /// The `alg` header parameter.
+    #[inline]
+    pub const fn algorithm(&self) -> &'static str {
+        match self {
+            JwsKey::Es256(_) => "ES256",
+            JwsKey::EdDsa(_) => "EdDSA",
+        }
+    }
+
+    #[inline]
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        match self {
+            JwsKey::Es256(key) => {
+                let signature: Signature = key.sign(message);
+
+                signature.to_bytes().to_vec()
+            },
+            JwsKey::EdDsa(key) => key.sign(message).to_bytes().to_vec(),
+        }
+    }
+}
+
+/// A payload signed as a compact JWS, with the dimensions of its QR code.
+#[derive(Debug, Clone)]
+pub struct SignedPayload {
+    /// The compact serialization, `<header>.<payload>.<signature>`, which can be passed to any `_from_str` function.
+    pub jws:        String,
+    /// The dimensions of the QR code of `jws`.
+    pub dimensions: QrDimensions,
+    /// Problems of the QR code, such as being too dense to scan reliably.
+    pub warnings:   Vec<RenderWarning>,
+}
+
+/// Sign a payload as a compact JWS and check that it fits in a QR code with the error correction level. The header only has the `alg` parameter to keep the code small.
+pub fn sign_jws<P: AsRef<[u8]>>(
+    payload: P,
+    key: &JwsKey,
+    ecc: QrCodeEcc,
+) -> Result<SignedPayload, QRCodeError> {
+    let mut jws = String::new();
+
+    base64::encode_url_to_string(
+        format!("{{\"alg\":\"{}\"}}", key.algorithm()).as_bytes(),
+        &mut jws,
+    );
+    jws.push('.');
+    base64::encode_url_to_string(payload.as_ref(), &mut jws);
+
+    let signature = key.sign(jws.as_bytes());
+
+    jws.push('.');
+    base64::encode_url_to_string(&signature, &mut jws);
+
+    let dimensions = estimate_from_str(&jws, ecc)?;
+
+    let mut warnings = Vec::new();
+
+    if dimensions.version > RenderWarning::MAX_COMFORTABLE_VERSION {
+        warnings.push(RenderWarning::DenseCode {
+            version: dimensions.version
+        });
+    }
+
+    Ok(SignedPayload {
+        jws,
+        dimensions,
+        warnings,
+    })
+}
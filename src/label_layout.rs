@@ -0,0 +1,173 @@
+#[cfg(feature = "ab_glyph")]
+use crate::Font;
+#[cfg(feature = "image")]
+use crate::OutputLimit;
+use crate::{Color, QRCodeError, QrCodeEcc, Validate};
+
+/// A line of text on a label. The text is a template whose `{name}` placeholders are replaced with the fields of each record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelText {
+    pub(crate) x:           usize,
+    pub(crate) y:           usize,
+    pub(crate) font_size:   usize,
+    pub(crate) template:    String,
+    pub(crate) color:       Color,
+    pub(crate) font_family: String,
+}
+
+impl LabelText {
+    /// Create a line of text whose baseline starts at `(x, y)`. `font_size` is in pixels.
+    #[inline]
+    pub fn new<S: Into<String>>(x: usize, y: usize, font_size: usize, template: S) -> Self {
+        LabelText {
+            x,
+            y,
+            font_size,
+            template: template.into(),
+            color: Color::BLACK,
+            font_family: String::from("sans-serif"),
+        }
+    }
+
+    /// Set the color of the text. The default value is `Color::BLACK`.
+    #[inline]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+
+        self
+    }
+
+    /// Set the font family of the text in SVG labels. The default value is `"sans-serif"`.
+    #[inline]
+    pub fn font_family<S: Into<String>>(mut self, font_family: S) -> Self {
+        self.font_family = font_family.into();
+
+        self
+    }
+}
+
+/// The layout of a label, i.e. a QR code and lines of text on a canvas.
+#[derive(Debug, Clone)]
+pub struct LabelLayout {
+    pub(crate) width:        usize,
+    pub(crate) height:       usize,
+    pub(crate) qr_x:         usize,
+    pub(crate) qr_y:         usize,
+    pub(crate) qr_size:      usize,
+    pub(crate) ecc:          QrCodeEcc,
+    pub(crate) background:   Color,
+    pub(crate) texts:        Vec<LabelText>,
+    #[cfg(feature = "image")]
+    pub(crate) output_limit: OutputLimit,
+    #[cfg(feature = "ab_glyph")]
+    pub(crate) font:         Option<Font>,
+}
+
+impl LabelLayout {
+    /// Create a `width` × `height` label with a `qr_size` × `qr_size` QR code whose upper-left corner is at `(qr_x, qr_y)`.
+    #[inline]
+    pub fn new(width: usize, height: usize, qr_x: usize, qr_y: usize, qr_size: usize) -> Self {
+        LabelLayout {
+            width,
+            height,
+            qr_x,
+            qr_y,
+            qr_size,
+            ecc: QrCodeEcc::Medium,
+            background: Color::WHITE,
+            texts: Vec::new(),
+            #[cfg(feature = "image")]
+            output_limit: OutputLimit::UNLIMITED,
+            #[cfg(feature = "ab_glyph")]
+            font: None,
+        }
+    }
+
+    /// Set the error correction level. The default value is `QrCodeEcc::Medium`.
+    #[inline]
+    pub fn ecc(mut self, ecc: QrCodeEcc) -> Self {
+        self.ecc = ecc;
+
+        self
+    }
+
+    /// Set the background color of the label. The default value is `Color::WHITE`.
+    #[inline]
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+
+        self
+    }
+
+    /// Add a line of text.
+    #[inline]
+    pub fn text(mut self, text: LabelText) -> Self {
+        self.texts.push(text);
+
+        self
+    }
+
+    /// Set the limits of the memory needed by PNG labels. There are no limits by default.
+    #[cfg(feature = "image")]
+    #[inline]
+    pub fn output_limit(mut self, output_limit: OutputLimit) -> Self {
+        self.output_limit = output_limit;
+
+        self
+    }
+
+    /// Set the font used to draw text in PNG labels.
+    #[cfg(feature = "ab_glyph")]
+    #[inline]
+    pub fn font(mut self, font: Option<Font>) -> Self {
+        self.font = font;
+
+        self
+    }
+}
+
+impl Validate for LabelText {
+    fn validate(&self) -> Result<(), QRCodeError> {
+        if self.font_size == 0 {
+            return Err(QRCodeError::invalid_input("font_size", "the font size must be positive"));
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for LabelLayout {
+    fn validate(&self) -> Result<(), QRCodeError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(QRCodeError::invalid_input("width", "the label must not be empty"));
+        }
+
+        if self.qr_size == 0 {
+            return Err(QRCodeError::invalid_input("qr_size", "the QR code must not be empty"));
+        }
+
+        if self.qr_x.saturating_add(self.qr_size) > self.width
+            || self.qr_y.saturating_add(self.qr_size) > self.height
+        {
+            return Err(QRCodeError::invalid_input(
+                "qr_size",
+                format!(
+                    "the QR code at ({}, {}) with the size {} exceeds the {} × {} label",
+                    self.qr_x, self.qr_y, self.qr_size, self.width, self.height
+                ),
+            ));
+        }
+
+        self.texts.iter().try_for_each(Validate::validate)
+    }
+}
+
+/// The image formats of labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LabelFormat {
+    Svg,
+    /// Drawing text in PNG labels needs the `ab_glyph` feature and a font.
+    #[cfg(feature = "image")]
+    Png,
+}
@@ -55,658 +55,412 @@ use qrcode_generator::QrCodeEcc;
 qrcode_generator::to_svg_to_file("Hello world!", QrCodeEcc::Low, 1024, None::<&str>, "tests/data/file_output.png").unwrap();
 ```
 
-## Low-level Usage
-
-### Raw Image Data
-
-The `to_image` and `to_image_buffer` functions can be used, if you want to modify your image.
-
-### Segments
-
-Every `to_*` function has a corresponding `_from_segments` function. You can concatenate segments by using different encoding methods, such as **numeric**, **alphanumeric** or **binary** to reduce the size (level) of your QR code matrix/image.
-
-```rust
-use qrcode_generator::{QrCodeEcc, QrSegment};
-
-let first = "1234567";
-
-let second = "ABCDEFG";
-
-let segments = [QrSegment::make_numeric(&first), QrSegment::make_alphanumeric(&second)];
-
-let result: Vec<Vec<bool>> = qrcode_generator::to_matrix_from_segments(&segments, QrCodeEcc::Low).unwrap();
-
-println!("{:?}", result);
-```
+## Payloads
 
-More segments optimization apporaches: [magiclen/qrcode-segments-optimizer](https://github.com/magiclen/qrcode-segments-optimizer)
-*/
+The `data` parameter accepts any type implementing `IntoQrPayload`, such as `&str`, `&[u8]`, `String`, `Vec<u8>`, `IpAddr` and a reference to any `AsRef<[u8]>` type. Other `AsRef<[u8]>` types, which the `data` parameter accepted by value before `IntoQrPayload` was introduced, need to be borrowed or wrapped in `QrBytes`, e.g. `QrBytes(bytes)`. Enable the `url` or `uuid` feature to pass `url::Url` or `uuid::Uuid` directly.
 
-pub extern crate qrcodegen;
+### Compressed Payloads
 
-mod qr_code_error;
+Enable the `miniz_oxide` feature to get `compress_payload` and `decompress_payload`. They deflate-compress binary data, such as JSON blobs, with a small marker so that more data fits in a QR code.
 
-use core::{mem::size_of, str::from_utf8};
-use std::{
-    fs::{self, File},
-    io::Write,
-    path::Path,
-};
+### Splitting Large Data
 
-#[cfg(feature = "image")]
-use image::codecs::png::{CompressionType, FilterType, PngEncoder};
-#[cfg(feature = "image")]
-use image::{ColorType, ImageBuffer, ImageEncoder, Luma};
-pub use qr_code_error::*;
-use qrcodegen::QrCode;
-pub use qrcodegen::{QrCodeEcc, QrSegment};
+`split_into_codes` splits data into payloads prefixed with `<index>/<total>:`, so that data too large for one QR code can be shown as several. It is a simple convention for ad-hoc tools, not Structured Append, so the receiver joins the chunks itself.
 
-#[inline]
-fn generate_qrcode<D: AsRef<[u8]>>(data: D, ecc: QrCodeEcc) -> Result<QrCode, QRCodeError> {
-    match from_utf8(data.as_ref()) {
-        Ok(text) => generate_qrcode_from_str(text, ecc),
-        Err(_) => {
-            let qr = match QrCode::encode_binary(data.as_ref(), ecc) {
-                Ok(qr) => qr,
-                Err(_) => return Err(QRCodeError::DataTooLong),
-            };
-
-            Ok(qr)
-        },
-    }
-}
-
-#[inline]
-fn generate_qrcode_from_str<S: AsRef<str>>(text: S, ecc: QrCodeEcc) -> Result<QrCode, QRCodeError> {
-    let qr = match QrCode::encode_text(text.as_ref(), ecc) {
-        Ok(qr) => qr,
-        Err(_) => return Err(QRCodeError::DataTooLong),
-    };
+```rust
+use qrcode_generator::QrCodeEcc;
 
-    Ok(qr)
-}
+let codes = qrcode_generator::split_into_codes(vec![0u8; 5000], 1000).unwrap();
 
-#[inline]
-fn generate_qrcode_from_segments(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-) -> Result<QrCode, QRCodeError> {
-    let qr = match QrCode::encode_segments(segments, ecc) {
-        Ok(qr) => qr,
-        Err(_) => return Err(QRCodeError::DataTooLong),
-    };
-
-    Ok(qr)
+for code in codes {
+    let svg: String = qrcode_generator::to_svg_to_string(code, QrCodeEcc::Low, 512, None::<&str>).unwrap();
 }
+```
 
-#[inline]
-fn to_matrix_inner(qr: QrCode) -> Vec<Vec<bool>> {
-    let size = qr.size();
-
-    let size_u = size as usize;
-
-    let mut rows = Vec::with_capacity(size_u);
-
-    for y in 0..size {
-        let mut row = Vec::with_capacity(size_u);
+### Signed Payloads
 
-        for x in 0..size {
-            row.push(qr.get_module(x, y));
-        }
+Enable the `crypto` feature to get `sign_jws`, which signs a payload as a compact JWS with an `ES256` or `EdDSA` key. It also estimates the QR code and warns if the code would be too dense to scan comfortably.
 
-        rows.push(row);
-    }
+```rust,ignore
+use qrcode_generator::{JwsKey, QrCodeEcc};
 
-    rows
-}
+let signed = qrcode_generator::sign_jws(r#"{"ticket":"A-001"}"#, &JwsKey::ed25519_from_bytes(&[7; 32]), QrCodeEcc::Medium).unwrap();
 
-#[inline]
-fn to_svg_inner<S: AsRef<str>, W: Write>(
-    qr: QrCode,
-    size: usize,
-    description: Option<S>,
-    mut writer: W,
-) -> Result<(), QRCodeError> {
-    let margin_size = 1;
+let svg = qrcode_generator::to_svg_to_string_from_str(&signed.jws, QrCodeEcc::Medium, 512, None::<&str>).unwrap();
+```
 
-    let s = qr.size();
+## Low-level Usage
 
-    let data_length = s as usize;
+### Raw Image Data
 
-    let data_length_with_margin = data_length + 2 * margin_size;
+The `to_image` and `to_image_buffer` functions can be used, if you want to modify your image. The `to_scanlines` function renders the same rows on demand, for encoders and streaming protocols which consume the image row by row. The `to_alpha_mask` function renders an 8-bit alpha mask, opaque where modules are dark, so that compositing engines can tint or texture the QR code when blending. The `to_image_with_pixel_format` function renders the same image in another `PixelFormat`, such as RGB565 in either byte order, BGR888 or grayscale, so that the buffer can be sent to the driver of an embedded display as it is.
 
-    let point_size = size / data_length_with_margin;
+### Content Hashes
 
-    if point_size == 0 {
-        return Err(QRCodeError::ImageSizeTooSmall);
-    }
+The `content_hash` function computes a stable 64-bit hash of the modules of a QR code, and `matrix_content_hash` computes the same hash of a matrix, so that caches and deduplication layers can key on the generated QR code instead of rendered images.
 
-    let margin = (size - (point_size * data_length)) / 2;
+### Segments
 
-    writer.write_fmt(format_args!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg width=\"{size}\" height=\"{size}\" shape-rendering=\"crispEdges\" version=\"1.1\" xmlns=\"http://www.w3.org/2000/svg\">\n"))?;
+Every `to_*` function has a corresponding `_from_segments` function. You can concatenate segments by using different encoding methods, such as **numeric**, **alphanumeric** or **binary** to reduce the size (level) of your QR code matrix/image.
 
-    match description {
-        Some(description) => {
-            let description = description.as_ref();
+```rust
+use qrcode_generator::{QrCodeEcc, QrSegment};
 
-            if !description.is_empty() {
-                writer.write_all(b"\t<desc>")?;
-                html_escape::encode_safe_to_writer(description, &mut writer)?;
-                writer.write_all(b"</desc>\n")?;
-            }
-        },
-        None => {
-            writer.write_fmt(format_args!(
-                "\t<desc>{name} {version} by magiclen.org</desc>\n",
-                name = env!("CARGO_PKG_NAME"),
-                version = env!("CARGO_PKG_VERSION")
-            ))?;
-        },
-    }
+let first = "1234567";
 
-    writer.write_fmt(format_args!(
-        "\t<rect width=\"{size}\" height=\"{size}\" fill=\"#FFF\"/>\n\t<path d=\""
-    ))?;
+let second = "ABCDEFG";
 
-    for i in 0..s {
-        for j in 0..s {
-            if qr.get_module(j, i) {
-                let x = j as usize * point_size + margin;
-                let y = i as usize * point_size + margin;
+let segments = [QrSegment::make_numeric(&first), QrSegment::make_alphanumeric(&second)];
 
-                writer.write_fmt(format_args!("M{x} {y}h{point_size}v{point_size}H{x}V{y}"))?;
-            }
-        }
-    }
+let result: Vec<Vec<bool>> = qrcode_generator::to_matrix_from_segments(&segments, QrCodeEcc::Low).unwrap();
 
-    writer.write_all(b"\"/>\n</svg>")?;
+println!("{:?}", result);
+```
 
-    writer.flush()?;
+More segments optimization apporaches: [magiclen/qrcode-segments-optimizer](https://github.com/magiclen/qrcode-segments-optimizer)
 
-    Ok(())
-}
+### Versions and Masks
 
-#[inline]
-fn to_svg_to_vec_inner<S: AsRef<str>>(
-    qr: QrCode,
-    size: usize,
-    description: Option<S>,
-) -> Result<Vec<u8>, QRCodeError> {
-    let mut svg = Vec::with_capacity(32768);
+The `Version` (`1` to `40`) and `Mask` (`0` to `7`) types have range checked constructors, clamping and iterators over their ranges, and convert to and from the types of the `qrcodegen` crate, so the advanced encoding options can be computed without depending on `qrcodegen` directly. For example, `Version::from_modules(25)` returns version `2`, and `Version::all()` walks every version to find one which fits a layout.
 
-    let margin_size = 1;
+## Labels
 
-    let s = qr.size();
+The `render_labels_from_csv` function merges every record of CSV text into a payload template and a `LabelLayout` (a QR code and lines of text), rendering one SVG or PNG label per record. Enable the `ab_glyph` feature and set a font to draw text in PNG labels.
 
-    let data_length = s as usize;
+```rust
+use qrcode_generator::{LabelFormat, LabelLayout, LabelText};
 
-    let data_length_with_margin = data_length + 2 * margin_size;
+let csv = "sku,name\nA-001,Widget\nA-002,Gadget\n";
 
-    let point_size = size / data_length_with_margin;
+let layout = LabelLayout::new(400, 200, 0, 0, 200).text(LabelText::new(210, 40, 24, "{name}"));
 
-    if point_size == 0 {
-        return Err(QRCodeError::ImageSizeTooSmall);
-    }
+let labels: Vec<Vec<u8>> = qrcode_generator::render_labels_from_csv(csv, "https://example.com/items/{sku}", &layout, LabelFormat::Svg).unwrap();
+```
 
-    let margin = (size - (point_size * data_length)) / 2;
+The `render_contact_sheet` and `render_labeled_contact_sheet` functions arrange a batch of QR codes, optionally with labels, into a grid in one PNG image for a quick visual check of bulk generations.
 
-    svg.write_fmt(format_args!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg width=\"{size}\" height=\"{size}\" shape-rendering=\"crispEdges\" version=\"1.1\" xmlns=\"http://www.w3.org/2000/svg\">\n"))?;
+Enable the `shaping` feature to shape the text drawn in raster images with rustybuzz and lay out mixed-direction text with the Unicode Bidirectional Algorithm, so Arabic, Hebrew and other complex scripts render correctly. Text in SVG images is shaped by the viewer.
 
-    match description {
-        Some(description) => {
-            let description = description.as_ref();
+## Animated QR Codes
 
-            if !description.is_empty() {
-                svg.extend_from_slice(b"\t<desc>");
-                html_escape::encode_safe_to_writer(description, &mut svg)?;
-                svg.extend_from_slice(b"</desc>\n");
-            }
-        },
-        None => {
-            svg.write_fmt(format_args!(
-                "\t<desc>{name} {version} by magiclen.org</desc>\n",
-                name = env!("CARGO_PKG_NAME"),
-                version = env!("CARGO_PKG_VERSION")
-            ))?;
-        },
-    }
+Enable the `bc-ur` feature to get a `UrEncoder` which splits large payloads into Blockchain Commons UR (BC-UR) fountain-coded parts. Its `frames` method produces an endless cycle of QR code matrices for animated QR codes.
 
-    svg.write_fmt(format_args!(
-        "\t<rect width=\"{size}\" height=\"{size}\" fill=\"#FFF\"/>\n\t<path d=\""
-    ))?;
+```rust,ignore
+use qrcode_generator::{QrCodeEcc, UrEncoder};
 
-    for i in 0..s {
-        for j in 0..s {
-            if qr.get_module(j, i) {
-                let x = j as usize * point_size + margin;
-                let y = i as usize * point_size + margin;
+let frames = UrEncoder::bytes(vec![0u8; 1000], 200).unwrap().frames(QrCodeEcc::Low);
 
-                svg.write_fmt(format_args!("M{x} {y}h{point_size}v{point_size}H{x}V{y}"))?;
-            }
-        }
-    }
+for matrix in frames.take(10) {
+    println!("{:?}", matrix.unwrap());
+}
+```
 
-    svg.write_all(b"\"/>\n</svg>")?;
+The `to_apng_to_vec` and `to_apng_to_writer` functions encode a sequence of payloads, such as the parts of a `UrEncoder`, to an endlessly looping APNG image with crisp 1-bit frames.
 
-    Ok(svg)
-}
+## Rotating QR Codes
 
-#[inline]
-fn to_svg_to_string_inner<S: AsRef<str>>(
-    qr: QrCode,
-    size: usize,
-    description: Option<S>,
-) -> Result<String, QRCodeError> {
-    let svg = to_svg_to_vec_inner(qr, size, description)?;
+Enable the `rotating` feature to get a `RotatingQr` which generates a new payload, a timestamp and its HMAC, for every interval. A screenshot of a check-in or attendance code stops working once the interval is over, and the verifier can check payloads with the same secret.
 
-    Ok(unsafe { String::from_utf8_unchecked(svg) })
-}
+```rust,ignore
+use std::time::Duration;
 
-#[inline]
-fn to_svg_to_file_inner<S: AsRef<str>, P: AsRef<Path>>(
-    qr: QrCode,
-    size: usize,
-    description: Option<S>,
-    path: P,
-) -> Result<(), QRCodeError> {
-    let path = path.as_ref();
-
-    let file = File::create(path)?;
-
-    to_svg_inner(qr, size, description, file).map_err(|err| {
-        if fs::remove_file(path).is_err() {
-            // do nothing
-        }
-        err
-    })
-}
+use qrcode_generator::{OutputFormat, OutputSpec, QrCodeEcc, RenderOptions, RotatingQr};
 
-fn to_image_inner(qr: QrCode, size: usize) -> Result<Vec<u8>, QRCodeError> {
-    if size >= 2usize.pow((size_of::<usize>() * 4) as u32) {
-        return Err(QRCodeError::ImageSizeTooLarge);
-    }
+let rotating = RotatingQr::new("secret", Duration::from_secs(30)).unwrap();
 
-    let margin_size = 1;
+let svg = rotating.render(&RenderOptions::new(QrCodeEcc::Low), OutputSpec::new(OutputFormat::Svg, 256)).unwrap();
 
-    let s = qr.size();
+assert!(rotating.verify(rotating.payload(), 1));
+```
 
-    let data_length = s as usize;
+## Compile-time Matrices
 
-    let data_length_with_margin = data_length + 2 * margin_size;
+Enable the `macros` feature to get a `qr_static!` macro which encodes a string literal when compiling, so fixed payloads cost nothing at runtime. The result is a `[[bool; N]; N]` array.
 
-    let point_size = size / data_length_with_margin;
+```rust,ignore
+use qrcode_generator::qr_static;
 
-    if point_size == 0 {
-        return Err(QRCodeError::ImageSizeTooSmall);
-    }
+const MATRIX: [[bool; 21]; 21] = qr_static!("Hello world!", QrCodeEcc::Low);
+```
 
-    let margin = (size - (point_size * data_length)) / 2;
+## JPEG
 
-    let length = size * size;
+Enable the `jpeg` feature to get `to_jpeg_to_vec` and `to_jpeg_to_writer`, which encode JPEG images with basic EXIF metadata, such as the software, a description, the creation time and a GPS location, so that asset-management systems can index them. The location can be taken from a `geo:` URI payload. It also adds `OutputFormat::Jpeg`, so `render` and `to_file` produce JPEG images (with the quality 90 and no metadata) for `.jpg` and `.jpeg` paths.
 
-    let mut img_raw: Vec<u8> = vec![255u8; length];
+```rust,ignore
+use std::time::SystemTime;
 
-    for i in 0..s {
-        for j in 0..s {
-            if qr.get_module(i, j) {
-                let x = i as usize * point_size + margin;
-                let y = j as usize * point_size + margin;
+use qrcode_generator::{ExifMetadata, QrCodeEcc};
 
-                for j in y..(y + point_size) {
-                    let offset = j * size;
-                    for i in x..(x + point_size) {
-                        img_raw[offset + i] = 0;
-                    }
-                }
-            }
-        }
-    }
+let payload = "geo:25.0340,121.5645";
 
-    Ok(img_raw)
-}
+let metadata = ExifMetadata::new().description(Some(String::from("Taipei 101"))).date_time(Some(SystemTime::now())).gps_from_geo_uri(payload).unwrap();
 
-#[cfg(feature = "image")]
-#[inline]
-fn to_png_inner<W: Write>(qr: QrCode, size: usize, writer: W) -> Result<(), QRCodeError> {
-    let img_raw = to_image_inner(qr, size)?;
+let jpeg: Vec<u8> = qrcode_generator::to_jpeg_to_vec(payload, QrCodeEcc::Low, 512, 90, &metadata).unwrap();
+```
 
-    let encoder = PngEncoder::new_with_quality(writer, CompressionType::Best, FilterType::NoFilter);
+## Output Limits
 
-    Ok(encoder.write_image(&img_raw, size as u32, size as u32, ColorType::L8.into())?)
-}
+If the size of images comes from users, such as a query parameter of a web service, set an `OutputLimit` in `RenderOptions` to reject requests for huge raster images before their buffers are allocated. An exceeded limit is reported as `QRCodeError::OutputLimitExceeded` with the requested and the allowed amounts. `ContactSheet` and `LabelLayout` take an `OutputLimit` as well. The `to_image*`, `to_png*`, `to_scanlines` and `to_apng*` functions have no limits, so do not pass sizes from users to them.
 
-#[cfg(feature = "image")]
-#[inline]
-fn to_png_to_vec_inner(qr: QrCode, size: usize) -> Result<Vec<u8>, QRCodeError> {
-    let mut png = Vec::with_capacity(4096);
+```rust
+use qrcode_generator::{OutputFormat, OutputLimit, OutputSpec, QrCodeEcc, RenderOptions};
 
-    to_png_inner(qr, size, &mut png)?;
+# #[cfg(feature = "image")] {
+let options = RenderOptions::new(QrCodeEcc::Low).output_limit(OutputLimit::new().max_pixels(Some(4096 * 4096)));
 
-    Ok(png)
-}
+assert!(qrcode_generator::render("Hello world!", &options, OutputSpec::new(OutputFormat::Png, 50000)).is_err());
+# }
+```
 
-#[cfg(feature = "image")]
-#[inline]
-fn to_png_to_file_inner<P: AsRef<Path>>(
-    qr: QrCode,
-    size: usize,
-    path: P,
-) -> Result<(), QRCodeError> {
-    let path = path.as_ref();
-
-    let file = File::create(path)?;
-
-    to_png_inner(qr, size, file).map_err(|err| {
-        if fs::remove_file(path).is_err() {
-            // do nothing
-        }
-        err
-    })
-}
+## Tracing
 
-#[cfg(feature = "image")]
-#[inline]
-fn to_image_buffer_inner(
-    qr: QrCode,
-    size: usize,
-) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
-    let img_raw = to_image_inner(qr, size)?;
+Enable the `tracing` feature to instrument encoding and rendering, including the `to_svg_*` and `to_png_*` functions, with `encode` and `render` spans at the debug level. Their fields record the payload length, the version, the output length, the duration and the error, so QR code generation shows up in existing traces.
 
-    let img: ImageBuffer<Luma<u8>, Vec<u8>> =
-        ImageBuffer::from_vec(size as u32, size as u32, img_raw).unwrap();
+## Preview
 
-    Ok(img)
-}
+Enable the `preview` feature to get `preview` and `preview_with_options`, which open a window showing the rendered QR code until it is closed or Esc is pressed. They are meant for debugging styling options.
 
-// TODO public functions
+```rust,ignore
+use qrcode_generator::{ModuleShape, QrCodeEcc, RenderOptions};
 
-/// Encode data to a QR code matrix.
-#[inline]
-pub fn to_matrix<D: AsRef<[u8]>>(data: D, ecc: QrCodeEcc) -> Result<Vec<Vec<bool>>, QRCodeError> {
-    Ok(to_matrix_inner(generate_qrcode(data, ecc)?))
-}
+qrcode_generator::preview_with_options("Hello world!", &RenderOptions::new(QrCodeEcc::Low).module_shape(ModuleShape::Dot)).unwrap();
+```
 
-/// Encode text to a QR code matrix.
-#[inline]
-pub fn to_matrix_from_str<S: AsRef<str>>(
-    text: S,
-    ecc: QrCodeEcc,
-) -> Result<Vec<Vec<bool>>, QRCodeError> {
-    Ok(to_matrix_inner(generate_qrcode_from_str(text, ecc)?))
-}
+## Bevy
 
-/// Encode segments to a QR code matrix.
-#[inline]
-pub fn to_matrix_from_segments(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-) -> Result<Vec<Vec<bool>>, QRCodeError> {
-    Ok(to_matrix_inner(generate_qrcode_from_segments(segments, ecc)?))
-}
+Enable the `bevy` feature to get `to_bevy_image`, which renders a QR code to a Bevy `Image` (RGBA8, sRGB) that can be spawned as a sprite or a UI image.
 
-/// Encode data to raw image in memory.
-pub fn to_image<D: AsRef<[u8]>>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<Vec<u8>, QRCodeError> {
-    to_image_inner(generate_qrcode(data, ecc)?, size)
-}
+```rust,ignore
+use qrcode_generator::{QrCodeEcc, RenderOptions};
 
-/// Encode text to raw image in memory.
-pub fn to_image_from_str<S: AsRef<str>>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<Vec<u8>, QRCodeError> {
-    to_image_inner(generate_qrcode_from_str(text, ecc)?, size)
-}
+let image = qrcode_generator::to_bevy_image("https://example.com/join", &RenderOptions::new(QrCodeEcc::Low), 256).unwrap();
 
-/// Encode segments to raw image in memory.
-pub fn to_image_from_segments(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<Vec<u8>, QRCodeError> {
-    to_image_inner(generate_qrcode_from_segments(segments, ecc)?, size)
-}
+commands.spawn(Sprite::from_image(images.add(image)));
+```
 
-/// Encode data to a SVG image in memory.
-#[inline]
-pub fn to_svg_to_string<D: AsRef<[u8]>, DESC: AsRef<str>>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-) -> Result<String, QRCodeError> {
-    to_svg_to_string_inner(generate_qrcode(data, ecc)?, size, description)
-}
+## Plotters
 
-/// Encode text to a SVG image in memory.
-#[inline]
-pub fn to_svg_to_string_from_str<S: AsRef<str>, DESC: AsRef<str>>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-) -> Result<String, QRCodeError> {
-    to_svg_to_string_inner(generate_qrcode_from_str(text, ecc)?, size, description)
-}
+Enable the `plotters` feature to get a `QrCodeElement` which can be drawn on any plotters drawing area.
 
-/// Encode segments to a SVG image in memory.
-#[inline]
-pub fn to_svg_to_string_from_segments<DESC: AsRef<str>>(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-) -> Result<String, QRCodeError> {
-    to_svg_to_string_inner(generate_qrcode_from_segments(segments, ecc)?, size, description)
-}
+```rust,ignore
+use plotters::prelude::*;
+use qrcode_generator::{QrCodeEcc, QrCodeElement};
 
-/// Encode data to a SVG image via a file path.
-#[inline]
-pub fn to_svg_to_file<D: AsRef<[u8]>, DESC: AsRef<str>, P: AsRef<Path>>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-    path: P,
-) -> Result<(), QRCodeError> {
-    to_svg_to_file_inner(generate_qrcode(data, ecc)?, size, description, path)
-}
+let root = BitMapBackend::new("chart.png", (640, 480)).into_drawing_area();
 
-/// Encode text to a SVG image via a file path.
-#[inline]
-pub fn to_svg_to_file_from_str<S: AsRef<str>, DESC: AsRef<str>, P: AsRef<Path>>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-    path: P,
-) -> Result<(), QRCodeError> {
-    to_svg_to_file_inner(generate_qrcode_from_str(text, ecc)?, size, description, path)
-}
+root.draw(&QrCodeElement::new("https://magiclen.org", QrCodeEcc::Low, (500, 340), 128).unwrap()).unwrap();
+```
 
-/// Encode segments to a SVG image via a file path.
-#[inline]
-pub fn to_svg_to_file_from_segments<DESC: AsRef<str>, P: AsRef<Path>>(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-    path: P,
-) -> Result<(), QRCodeError> {
-    to_svg_to_file_inner(generate_qrcode_from_segments(segments, ecc)?, size, description, path)
-}
+## Cargo Features
 
-/// Encode data to a SVG image via a writer.
-#[inline]
-pub fn to_svg_to_writer<D: AsRef<[u8]>, DESC: AsRef<str>, W: Write>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-    writer: &mut W,
-) -> Result<(), QRCodeError> {
-    to_svg_inner(generate_qrcode(data, ecc)?, size, description, writer)
-}
+There are no separate features per output. The SVG, raw and matrix outputs only need `qrcodegen`, so they are always available. Raster outputs, such as the `to_png_*`, `to_encoded_image*` and `to_image_buffer*` functions, need the `image` feature, which is enabled by default.
 
-/// Encode text to a SVG image via a writer.
-#[inline]
-pub fn to_svg_to_writer_from_str<S: AsRef<str>, DESC: AsRef<str>, W: Write>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-    writer: &mut W,
-) -> Result<(), QRCodeError> {
-    to_svg_inner(generate_qrcode_from_str(text, ecc)?, size, description, writer)
-}
+For example, a server which only emits SVG images can use `default-features = false`, so that the `image` crate is not compiled at all. The `render` functions support SVG, PDF and HTML outputs with any features.
+*/
 
-/// Encode segments to a SVG image via a writer.
-#[inline]
-pub fn to_svg_to_writer_from_segments<DESC: AsRef<str>, W: Write>(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-    description: Option<DESC>,
-    writer: &mut W,
-) -> Result<(), QRCodeError> {
-    to_svg_inner(generate_qrcode_from_segments(segments, ecc)?, size, description, writer)
-}
+#![forbid(unsafe_code)]
+#![deny(clippy::unwrap_used, clippy::expect_used)]
 
-#[cfg(feature = "image")]
-/// Encode data to a PNG image in memory.
-#[inline]
-pub fn to_png_to_vec<D: AsRef<[u8]>>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<Vec<u8>, QRCodeError> {
-    to_png_to_vec_inner(generate_qrcode(data, ecc)?, size)
-}
+pub extern crate qrcodegen;
 
 #[cfg(feature = "image")]
-/// Encode text to a PNG image in memory.
-#[inline]
-pub fn to_png_to_vec_from_str<S: AsRef<str>>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<Vec<u8>, QRCodeError> {
-    to_png_to_vec_inner(generate_qrcode_from_str(text, ecc)?, size)
-}
-
+mod alpha_mask;
 #[cfg(feature = "image")]
-/// Encode segments to a PNG image in memory.
-#[inline]
-pub fn to_png_to_vec_from_segments(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<Vec<u8>, QRCodeError> {
-    to_png_to_vec_inner(generate_qrcode_from_segments(segments, ecc)?, size)
-}
-
+mod apng;
+mod artifact;
+mod base64;
+#[cfg(feature = "bevy")]
+mod bevy_texture;
+mod border;
+mod box_shadow_renderer;
+pub mod build;
+#[cfg(feature = "bc-ur")]
+mod bytewords;
+mod classify;
+mod cmyk_color;
+mod color;
+#[cfg(feature = "miniz_oxide")]
+mod compressed_payload;
 #[cfg(feature = "image")]
-/// Encode data to a PNG image via a file path.
-#[inline]
-pub fn to_png_to_file<D: AsRef<[u8]>, P: AsRef<Path>>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-    path: P,
-) -> Result<(), QRCodeError> {
-    to_png_to_file_inner(generate_qrcode(data, ecc)?, size, path)
-}
-
+mod contact_sheet;
+mod content_hash;
+#[cfg(any(feature = "image", feature = "bc-ur"))]
+mod crc32;
+mod csv;
+#[cfg(feature = "miniz_oxide")]
+mod decompress_payload_error;
+mod dithering;
+mod ecc;
+mod encode;
+mod escape;
+mod estimate;
+#[cfg(feature = "jpeg")]
+mod exif_metadata;
+mod file_operation;
+mod file_options;
+mod file_output;
+mod fmt_writer;
+#[cfg(feature = "ab_glyph")]
+mod font;
+#[cfg(feature = "bc-ur")]
+mod fountain;
+mod icc_profile;
+mod into_qr_payload;
+#[cfg(feature = "jpeg")]
+mod jpeg;
+#[cfg(feature = "crypto")]
+mod jws;
+mod label_layout;
+mod logo_area;
+mod mail_merge;
+mod mask;
+mod mask_penalty;
+mod matrix_output;
+mod module_layout;
+mod module_shape;
+mod optimized_url;
+mod output_format;
+mod output_limit;
+mod output_profile;
+mod parse_ecc_error;
+mod pattern_box;
+mod pdf_renderer;
+mod pixel_format;
+#[cfg(feature = "plotters")]
+mod plotters_element;
 #[cfg(feature = "image")]
-/// Encode text to a PNG image via a file path.
-#[inline]
-pub fn to_png_to_file_from_str<S: AsRef<str>, P: AsRef<Path>>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-    path: P,
-) -> Result<(), QRCodeError> {
-    to_png_to_file_inner(generate_qrcode_from_str(text, ecc)?, size, path)
-}
-
+mod png_chunk;
 #[cfg(feature = "image")]
-/// Encode text to a PNG image via a file path.
-#[inline]
-pub fn to_png_to_file_from_segments<P: AsRef<Path>>(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-    path: P,
-) -> Result<(), QRCodeError> {
-    to_png_to_file_inner(generate_qrcode_from_segments(segments, ecc)?, size, path)
-}
+mod png_output;
+#[cfg(feature = "preview")]
+mod preview;
+mod print_marks;
+mod qr_code_error;
+mod qr_dimensions;
+mod qr_service;
+#[cfg(any(feature = "image", feature = "tiff"))]
+mod raster;
+mod raw_output;
+mod rect;
+mod region;
+mod render_options;
+mod render_output;
+mod render_warning;
+#[cfg(feature = "rotating")]
+mod rotating_qr;
+mod scanlines;
+mod split_codes;
+mod spot_color;
+mod srcset;
+mod svg_filter;
+mod svg_output;
+mod svg_renderer;
+mod svg_template;
+#[cfg(all(feature = "image", feature = "ab_glyph"))]
+mod text_raster;
+mod theme;
+#[cfg(feature = "tiff")]
+mod tiff_renderer;
+mod trace;
+#[cfg(feature = "bc-ur")]
+mod ur_encoder;
+#[cfg(feature = "bc-ur")]
+mod ur_encoder_error;
+mod validate;
+mod version;
+mod zone;
 
 #[cfg(feature = "image")]
-/// Encode data to a PNG image via a writer.
-#[inline]
-pub fn to_png_to_writer<D: AsRef<[u8]>, W: Write>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-    writer: &mut W,
-) -> Result<(), QRCodeError> {
-    to_png_inner(generate_qrcode(data, ecc)?, size, writer)
-}
-
+pub use alpha_mask::*;
 #[cfg(feature = "image")]
-/// Encode text to a PNG image via a writer.
-#[inline]
-pub fn to_png_to_writer_from_str<S: AsRef<str>, W: Write>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-    writer: &mut W,
-) -> Result<(), QRCodeError> {
-    to_png_inner(generate_qrcode_from_str(text, ecc)?, size, writer)
-}
-
+pub use apng::*;
+pub use artifact::*;
+#[cfg(feature = "bevy")]
+pub use bevy_texture::*;
+pub use border::*;
+pub use cmyk_color::*;
+pub use color::*;
+#[cfg(feature = "miniz_oxide")]
+pub use compressed_payload::*;
 #[cfg(feature = "image")]
-/// Encode segments to a PNG image via a writer.
-#[inline]
-pub fn to_png_to_writer_from_segments<W: Write>(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-    writer: &mut W,
-) -> Result<(), QRCodeError> {
-    to_png_inner(generate_qrcode_from_segments(segments, ecc)?, size, writer)
-}
-
+pub use contact_sheet::*;
+pub use content_hash::*;
+#[cfg(feature = "miniz_oxide")]
+pub use decompress_payload_error::*;
+pub use dithering::*;
+pub use ecc::*;
+use encode::{
+    generate_qrcode, generate_qrcode_advanced, generate_qrcode_from_segments,
+    generate_qrcode_from_str, payload_segments,
+};
+pub use estimate::*;
+#[cfg(feature = "jpeg")]
+pub use exif_metadata::*;
+pub use file_operation::*;
+pub use file_options::*;
+pub use file_output::*;
+#[cfg(feature = "ab_glyph")]
+pub use font::*;
+pub use icc_profile::*;
 #[cfg(feature = "image")]
-/// Encode data to a image buffer.
-pub fn to_image_buffer<D: AsRef<[u8]>>(
-    data: D,
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
-    to_image_buffer_inner(generate_qrcode(data, ecc)?, size)
-}
-
+pub use image::ImageFormat;
+pub use into_qr_payload::*;
+#[cfg(feature = "jpeg")]
+pub use jpeg::*;
+#[cfg(feature = "crypto")]
+pub use jws::*;
+pub use label_layout::*;
+pub use logo_area::*;
+pub use mail_merge::*;
+pub use mask::*;
+pub use mask_penalty::*;
+#[cfg(feature = "bc-ur")]
+use matrix_output::to_matrix_inner;
+pub use matrix_output::*;
+use module_layout::{check_image_size, layout};
+pub use module_shape::*;
+pub use optimized_url::*;
+pub use output_format::*;
+pub use output_limit::*;
+pub use output_profile::*;
+pub use parse_ecc_error::*;
+pub use pattern_box::*;
+pub use pixel_format::*;
+#[cfg(feature = "plotters")]
+pub use plotters_element::*;
 #[cfg(feature = "image")]
-/// Encode text to a image buffer.
-pub fn to_image_buffer_from_str<S: AsRef<str>>(
-    text: S,
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
-    to_image_buffer_inner(generate_qrcode_from_str(text, ecc)?, size)
-}
-
+pub use png_output::*;
+#[cfg(feature = "preview")]
+pub use preview::*;
+pub use qr_code_error::*;
+pub use qr_dimensions::*;
+pub use qr_service::*;
+#[cfg(feature = "macros")]
+pub use qrcode_generator_macros::qr_static;
+pub use qrcodegen::{QrCodeEcc, QrSegment};
 #[cfg(feature = "image")]
-/// Encode segments to a image buffer.
-pub fn to_image_buffer_from_segments<S: AsRef<str>>(
-    segments: &[QrSegment],
-    ecc: QrCodeEcc,
-    size: usize,
-) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
-    to_image_buffer_inner(generate_qrcode_from_segments(segments, ecc)?, size)
-}
+use raw_output::to_image_inner;
+pub use raw_output::*;
+pub use rect::*;
+pub use region::*;
+pub use render_options::*;
+use render_output::render_to_vec_inner;
+pub use render_output::*;
+pub use render_warning::*;
+#[cfg(feature = "rotating")]
+pub use rotating_qr::*;
+pub use scanlines::*;
+pub use split_codes::*;
+pub use spot_color::*;
+pub use srcset::*;
+pub use svg_filter::*;
+pub use svg_output::*;
+pub use theme::*;
+#[cfg(feature = "bc-ur")]
+pub use ur_encoder::*;
+#[cfg(feature = "bc-ur")]
+pub use ur_encoder_error::*;
+pub use validate::*;
+pub use version::*;
+pub use zone::*;
@@ -0,0 +1,153 @@
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    classify::make_segments, generate_qrcode_advanced, layout, payload_segments, IntoQrPayload,
+    QRCodeError, RenderOptions,
+};
+
+// the tables of ISO/IEC 18004, indexed by the error correction level and the version
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// The share of the theoretical budget which is used, leaving room for codewords which are only partly covered and for printing and scanning defects.
+const SAFETY_FACTOR: f32 = 0.75;
+
+/// The number of codewords which can be corrected. Small versions reserve some error correction codewords for detecting misdecodes.
+fn correctable_codewords(version: u8, ecc: QrCodeEcc) -> u32 {
+    let level = match ecc {
+        QrCodeEcc::Low => 0,
+        QrCodeEcc::Medium => 1,
+        QrCodeEcc::Quartile => 2,
+        QrCodeEcc::High => 3,
+    };
+
+    let version = usize::from(version);
+
+    let misdecode_protection = match (version, level) {
+        (1, 0) => 3,
+        (1, 1) | (2, 0) => 2,
+        (1, _) | (3, 0) => 1,
+        _ => 0,
+    };
+
+    let blocks = u32::from(NUM_ERROR_CORRECTION_BLOCKS[level][version]);
+    let ecc_codewords = u32::from(ECC_CODEWORDS_PER_BLOCK[level][version]);
+
+    blocks * (ecc_codewords / 2) - misdecode_protection
+}
+
+/// Compute the largest share (from `0` to `1`) of the area of a QR code symbol, without the quiet zone, which a centered logo can cover while its codewords can still be recovered. The version is clamped to `1` to `40`. The logo also stays clear of the finder patterns and their separators.
+pub fn max_logo_area(version: u8, ecc: QrCodeEcc) -> f32 {
+    let version = version.clamp(1, 40);
+
+    let modules = f32::from(version) * 4.0 + 17.0;
+
+    // every codeword has 8 modules
+    let budget = correctable_codewords(version, ecc) as f32 * 8.0 / (modules * modules);
+
+    // a centered square wider than this touches the separators of the finder patterns
+    let clear = ((modules - 16.0) / modules).powi(2);
+
+    (budget * SAFETY_FACTOR).min(clear)
+}
+
+fn max_logo_size_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<usize, QRCodeError> {
+    let (point_size, _) = layout(qr, size, options.quiet_zone)?;
+
+    let area = max_logo_area(qr.version().value(), qr.error_correction_level());
+
+    let modules = area.sqrt() * qr.size() as f32;
+
+    Ok((modules * point_size as f32) as usize)
+}
+
+/// Compute the width and height in pixels of the largest centered square logo which can be put on data rendered with options at `size`. See `max_logo_area`.
+#[inline]
+pub fn max_logo_size<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<usize, QRCodeError> {
+    max_logo_size_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Compute the width and height in pixels of the largest centered square logo which can be put on text rendered with options at `size`. See `max_logo_area`.
+#[inline]
+pub fn max_logo_size_from_str<S: AsRef<str>>(
+    text: S,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<usize, QRCodeError> {
+    max_logo_size_inner(
+        &generate_qrcode_advanced(
+            &make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Compute the width and height in pixels of the largest centered square logo which can be put on segments rendered with options at `size`. See `max_logo_area`.
+#[inline]
+pub fn max_logo_size_from_segments(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    size: usize,
+) -> Result<usize, QRCodeError> {
+    max_logo_size_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        size,
+    )
+}
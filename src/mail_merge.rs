@@ -0,0 +1,195 @@
+use std::io::Write;
+
+use qrcodegen::QrCode;
+
+#[cfg(feature = "image")]
+use crate::render_options::DEFAULT_RENDER_OPTIONS;
+#[cfg(feature = "image")]
+use crate::{
+    check_image_size,
+    raster::{encode_png, to_rgba, PNG_BYTES_PER_PIXEL, RGBA_BYTES_PER_PIXEL},
+};
+use crate::{
+    csv::{fill_template, parse_csv},
+    escape, generate_qrcode_from_str, layout, LabelFormat, LabelLayout, QRCodeError, Validate,
+};
+
+fn to_svg_label_inner<K: AsRef<str>, V: AsRef<str>, W: Write>(
+    qr: &QrCode,
+    label: &LabelLayout,
+    fields: &[(K, V)],
+    mut writer: W,
+) -> Result<(), QRCodeError> {
+    let (point_size, margin) = layout(qr, label.qr_size, 1)?;
+
+    let s = qr.size();
+
+    let width = label.width;
+    let height = label.height;
+    let qr_size = label.qr_size;
+
+    writer.write_fmt(format_args!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg width=\"{width}\" height=\"{height}\" shape-rendering=\"crispEdges\" version=\"1.1\" xmlns=\"http://www.w3.org/2000/svg\">\n"))?;
+
+    writer.write_fmt(format_args!(
+        "\t<rect width=\"{width}\" height=\"{height}\" {}/>\n\t<rect x=\"{x}\" y=\"{y}\" \
+         width=\"{qr_size}\" height=\"{qr_size}\" fill=\"#FFF\"/>\n\t<path d=\"",
+        label.background.to_svg_fill(),
+        x = label.qr_x,
+        y = label.qr_y,
+    ))?;
+
+    for i in 0..s {
+        for j in 0..s {
+            if qr.get_module(j, i) {
+                let x = j as usize * point_size + margin + label.qr_x;
+                let y = i as usize * point_size + margin + label.qr_y;
+
+                writer.write_fmt(format_args!("M{x} {y}h{point_size}v{point_size}H{x}V{y}"))?;
+            }
+        }
+    }
+
+    writer.write_all(b"\"/>\n")?;
+
+    for text in label.texts.iter() {
+        writer.write_all(b"\t<text x=\"")?;
+        writer.write_fmt(format_args!("{}\" y=\"{}\" font-family=\"", text.x, text.y))?;
+        escape::encode_double_quoted_attribute_to_writer(&text.font_family, &mut writer)?;
+        writer.write_fmt(format_args!(
+            "\" font-size=\"{}\" {}>",
+            text.font_size,
+            text.color.to_svg_fill()
+        ))?;
+        escape::encode_safe_to_writer(&fill_template(&text.template, fields)?, &mut writer)?;
+        writer.write_all(b"</text>\n")?;
+    }
+
+    writer.write_all(b"</svg>")?;
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+fn to_png_label_inner<K: AsRef<str>, V: AsRef<str>, W: Write>(
+    qr: &QrCode,
+    label: &LabelLayout,
+    fields: &[(K, V)],
+    writer: W,
+) -> Result<(), QRCodeError> {
+    check_image_size(label.width.max(label.height))?;
+
+    let width = label.width;
+    let height = label.height;
+
+    label.output_limit.check_dimensions(width, height, PNG_BYTES_PER_PIXEL)?;
+
+    let length = (width * height).checked_mul(4).ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+    let mut rgba = Vec::with_capacity(length);
+
+    for _ in 0..(width * height) {
+        rgba.extend_from_slice(&label.background.to_array());
+    }
+
+    let qr_rgba = to_rgba(qr, label.qr_size, &DEFAULT_RENDER_OPTIONS, RGBA_BYTES_PER_PIXEL)?;
+
+    // clip the QR code to the label, which may leave nothing visible
+    let visible_width = label.qr_size.min(width.saturating_sub(label.qr_x));
+    let visible_height =
+        if visible_width == 0 { 0 } else { label.qr_size.min(height.saturating_sub(label.qr_y)) };
+
+    for row in 0..visible_height {
+        let src = row * label.qr_size * 4;
+        let dst = ((label.qr_y + row) * width + label.qr_x) * 4;
+
+        rgba[dst..(dst + visible_width * 4)]
+            .copy_from_slice(&qr_rgba[src..(src + visible_width * 4)]);
+    }
+
+    let texts = label
+        .texts
+        .iter()
+        .map(|text| Ok((text, fill_template(&text.template, fields)?)))
+        .collect::<Result<Vec<_>, QRCodeError>>()?;
+
+    if !texts.is_empty() {
+        #[cfg(feature = "ab_glyph")]
+        {
+            let font = label.font.as_ref().ok_or(QRCodeError::MissingFont)?;
+
+            for (text, content) in texts {
+                crate::text_raster::draw_text(
+                    &mut rgba,
+                    width,
+                    height,
+                    font,
+                    text.font_size as f32,
+                    text.x as f32,
+                    text.y as f32,
+                    &content,
+                    text.color,
+                );
+            }
+        }
+
+        #[cfg(not(feature = "ab_glyph"))]
+        return Err(QRCodeError::MissingFont);
+    }
+
+    encode_png(rgba, width, height, None, writer)
+}
+
+/// Render a label for a record of fields, given as `(name, value)` pairs. `{name}` placeholders in the payload template and the text templates are replaced with the values.
+pub fn render_label<K: AsRef<str>, V: AsRef<str>, T: AsRef<str>>(
+    fields: &[(K, V)],
+    payload_template: T,
+    label: &LabelLayout,
+    format: LabelFormat,
+) -> Result<Vec<u8>, QRCodeError> {
+    label.validate()?;
+
+    let payload = fill_template(payload_template.as_ref(), fields)?;
+
+    let qr = generate_qrcode_from_str(payload, label.ecc)?;
+
+    let mut image = Vec::with_capacity(32768);
+
+    match format {
+        LabelFormat::Svg => to_svg_label_inner(&qr, label, fields, &mut image)?,
+        #[cfg(feature = "image")]
+        LabelFormat::Png => to_png_label_inner(&qr, label, fields, &mut image)?,
+    }
+
+    Ok(image)
+}
+
+/// Render a label for every record of CSV text. The first row is the header which names the fields.
+pub fn render_labels_from_csv<S: AsRef<str>, T: AsRef<str>>(
+    csv: S,
+    payload_template: T,
+    label: &LabelLayout,
+    format: LabelFormat,
+) -> Result<Vec<Vec<u8>>, QRCodeError> {
+    label.validate()?;
+
+    let mut rows = parse_csv(csv.as_ref())?.into_iter();
+
+    let header = match rows.next() {
+        Some(header) => header,
+        None => return Ok(Vec::new()),
+    };
+
+    rows.map(|row| {
+        if row.len() != header.len() {
+            return Err(QRCodeError::InvalidCsv);
+        }
+
+        let fields: Vec<(&str, &str)> =
+            header.iter().map(String::as_str).zip(row.iter().map(String::as_str)).collect();
+
+        render_label(&fields, payload_template.as_ref(), label, format)
+    })
+    .collect()
+}
@@ -0,0 +1,94 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+};
+
+use crate::QRCodeError;
+
+/// A mask pattern from `0` to `7`, which is applied to the data modules to avoid patterns that confuse scanners. It can be converted to/from `qrcodegen::Mask`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mask(u8);
+
+impl Mask {
+    /// The last mask pattern, `7`.
+    pub const MAX: Mask = Mask(7);
+    /// The first mask pattern, `0`.
+    pub const MIN: Mask = Mask(0);
+
+    /// Create a mask pattern. `None` means it is out of the range.
+    #[inline]
+    pub const fn new(mask: u8) -> Option<Self> {
+        if mask <= Self::MAX.0 {
+            Some(Mask(mask))
+        } else {
+            None
+        }
+    }
+
+    /// Create a mask pattern, clamping the value to the range.
+    #[inline]
+    pub const fn clamped(mask: u8) -> Self {
+        if mask > Self::MAX.0 {
+            Self::MAX
+        } else {
+            Mask(mask)
+        }
+    }
+
+    /// Get the value from `0` to `7`.
+    #[inline]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+
+    /// Iterate over the mask patterns in `range`, clamped to `0` to `7`.
+    #[inline]
+    pub fn range(range: RangeInclusive<u8>) -> impl DoubleEndedIterator<Item = Self> {
+        let (start, end) = (Self::clamped(*range.start()).0, Self::clamped(*range.end()).0);
+
+        (start..=end).map(Mask)
+    }
+
+    /// Iterate over every mask pattern from `0` to `7`, in the order of `mask_penalties`.
+    #[inline]
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> {
+        Self::range(Self::MIN.0..=Self::MAX.0)
+    }
+}
+
+impl Display for Mask {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl TryFrom<u8> for Mask {
+    type Error = QRCodeError;
+
+    #[inline]
+    fn try_from(mask: u8) -> Result<Self, Self::Error> {
+        Mask::new(mask).ok_or_else(|| QRCodeError::invalid_input("mask", "must be between 0 and 7"))
+    }
+}
+
+impl From<Mask> for u8 {
+    #[inline]
+    fn from(mask: Mask) -> Self {
+        mask.0
+    }
+}
+
+impl From<Mask> for qrcodegen::Mask {
+    #[inline]
+    fn from(mask: Mask) -> Self {
+        qrcodegen::Mask::new(mask.0)
+    }
+}
+
+impl From<qrcodegen::Mask> for Mask {
+    #[inline]
+    fn from(mask: qrcodegen::Mask) -> Self {
+        Mask(mask.value())
+    }
+}
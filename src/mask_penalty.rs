@@ -0,0 +1,168 @@
+use qrcodegen::QrCode;
+
+use crate::{
+    classify, generate_qrcode_advanced, payload_segments, IntoQrPayload, Mask, QRCodeError,
+    QrCodeEcc, QrSegment,
+};
+
+const PENALTY_N1: u32 = 3;
+const PENALTY_N2: u32 = 3;
+const PENALTY_N3: u32 = 40;
+const PENALTY_N4: u32 = 10;
+
+/// The lengths of the latest 7 runs of a row or a column, used to find finder-like patterns.
+struct FinderPenalty {
+    qr_size:     u32,
+    run_history: [u32; 7],
+}
+
+impl FinderPenalty {
+    #[inline]
+    fn new(qr_size: u32) -> Self {
+        FinderPenalty {
+            qr_size,
+            run_history: [0; 7],
+        }
+    }
+
+    fn add_history(&mut self, mut run_length: u32) {
+        // the light border before the first run
+        if self.run_history[0] == 0 {
+            run_length += self.qr_size;
+        }
+
+        self.run_history.copy_within(0..6, 1);
+        self.run_history[0] = run_length;
+    }
+
+    /// Count the 1:1:3:1:1 patterns with 4 light modules on either side. It can only be called right after a light run is added.
+    fn count_patterns(&self) -> u32 {
+        let rh = &self.run_history;
+
+        let n = rh[1];
+
+        let core = n > 0 && rh[2] == n && rh[3] == n * 3 && rh[4] == n && rh[5] == n;
+
+        u32::from(core && rh[0] >= n * 4 && rh[6] >= n)
+            + u32::from(core && rh[6] >= n * 4 && rh[0] >= n)
+    }
+
+    fn terminate_and_count(mut self, run_color: bool, mut run_length: u32) -> u32 {
+        if run_color {
+            self.add_history(run_length);
+
+            run_length = 0;
+        }
+
+        // the light border after the last run
+        run_length += self.qr_size;
+
+        self.add_history(run_length);
+
+        self.count_patterns()
+    }
+}
+
+/// Compute the penalty score of a QR code by the rules of ISO/IEC 18004. A lower score means a better module distribution.
+pub(crate) fn penalty_score(qr: &QrCode) -> u32 {
+    let size = qr.size();
+
+    let mut result = 0;
+
+    for transposed in [false, true] {
+        let module =
+            |a: i32, b: i32| if transposed { qr.get_module(b, a) } else { qr.get_module(a, b) };
+
+        for b in 0..size {
+            let mut run_color = false;
+            let mut run_length = 0;
+            let mut run_history = FinderPenalty::new(size as u32);
+
+            for a in 0..size {
+                if module(a, b) == run_color {
+                    run_length += 1;
+
+                    if run_length == 5 {
+                        result += PENALTY_N1;
+                    } else if run_length > 5 {
+                        result += 1;
+                    }
+                } else {
+                    run_history.add_history(run_length);
+
+                    if !run_color {
+                        result += run_history.count_patterns() * PENALTY_N3;
+                    }
+
+                    run_color = module(a, b);
+                    run_length = 1;
+                }
+            }
+
+            result += run_history.terminate_and_count(run_color, run_length) * PENALTY_N3;
+        }
+    }
+
+    for y in 0..(size - 1) {
+        for x in 0..(size - 1) {
+            let color = qr.get_module(x, y);
+
+            if color == qr.get_module(x + 1, y)
+                && color == qr.get_module(x, y + 1)
+                && color == qr.get_module(x + 1, y + 1)
+            {
+                result += PENALTY_N2;
+            }
+        }
+    }
+
+    let mut dark = 0i32;
+
+    for y in 0..size {
+        for x in 0..size {
+            dark += i32::from(qr.get_module(x, y));
+        }
+    }
+
+    let total = size * size;
+
+    // the smallest k such that (45 - 5k)% <= dark / total <= (55 + 5k)%
+    let k = ((dark * 20 - total * 10).abs() + total - 1) / total - 1;
+
+    result + k as u32 * PENALTY_N4
+}
+
+/// Compute the penalty scores of the 8 mask patterns, indexed by the mask number.
+fn mask_penalties_inner(segments: &[QrSegment], ecc: QrCodeEcc) -> Result<[u32; 8], QRCodeError> {
+    let mut penalties = [0; 8];
+
+    for (mask, penalty) in Mask::all().zip(penalties.iter_mut()) {
+        *penalty = penalty_score(&generate_qrcode_advanced(segments, ecc, None, Some(mask))?);
+    }
+
+    Ok(penalties)
+}
+
+/// Compute the penalty scores of the 8 mask patterns (indexed by the mask number) for data. The mask with the lowest score is chosen by default, and another one can be chosen by `RenderOptions::mask`.
+#[inline]
+pub fn mask_penalties<D: IntoQrPayload>(data: D, ecc: QrCodeEcc) -> Result<[u32; 8], QRCodeError> {
+    mask_penalties_inner(&payload_segments(data), ecc)
+}
+
+/// Compute the penalty scores of the 8 mask patterns (indexed by the mask number) for text. The mask with the lowest score is chosen by default, and another one can be chosen by `RenderOptions::mask`.
+#[inline]
+pub fn mask_penalties_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+) -> Result<[u32; 8], QRCodeError> {
+    mask_penalties_inner(&classify::make_segments(text.as_ref().as_bytes()), ecc)
+}
+
+/// Compute the penalty scores of the 8 mask patterns (indexed by the mask number) for segments. The mask with the lowest score is chosen by default, and another one can be chosen by `RenderOptions::mask`.
+#[inline]
+pub fn mask_penalties_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+) -> Result<[u32; 8], QRCodeError> {
+    mask_penalties_inner(segments, ecc)
+}
@@ -0,0 +1,51 @@
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str, IntoQrPayload,
+    QRCodeError,
+};
+
+#[inline]
+pub(crate) fn to_matrix_inner(qr: QrCode) -> Vec<Vec<bool>> {
+    let size = qr.size();
+
+    let size_u = size as usize;
+
+    let mut rows = Vec::with_capacity(size_u);
+
+    for y in 0..size {
+        let mut row = Vec::with_capacity(size_u);
+
+        for x in 0..size {
+            row.push(qr.get_module(x, y));
+        }
+
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Encode data to a QR code matrix.
+#[inline]
+pub fn to_matrix<D: IntoQrPayload>(data: D, ecc: QrCodeEcc) -> Result<Vec<Vec<bool>>, QRCodeError> {
+    Ok(to_matrix_inner(generate_qrcode(data, ecc)?))
+}
+
+/// Encode text to a QR code matrix.
+#[inline]
+pub fn to_matrix_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+) -> Result<Vec<Vec<bool>>, QRCodeError> {
+    Ok(to_matrix_inner(generate_qrcode_from_str(text, ecc)?))
+}
+
+/// Encode segments to a QR code matrix.
+#[inline]
+pub fn to_matrix_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+) -> Result<Vec<Vec<bool>>, QRCodeError> {
+    Ok(to_matrix_inner(generate_qrcode_from_segments(segments, ecc)?))
+}
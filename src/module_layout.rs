@@ -0,0 +1,39 @@
+use core::mem::size_of;
+
+use qrcodegen::QrCode;
+
+use crate::QRCodeError;
+
+/// Make sure the number of pixels of a `size` × `size` image does not overflow.
+#[inline]
+pub(crate) fn check_image_size(size: usize) -> Result<(), QRCodeError> {
+    if size >= 2usize.pow((size_of::<usize>() * 4) as u32) {
+        return Err(QRCodeError::ImageSizeTooLarge);
+    }
+
+    Ok(())
+}
+
+/// Compute the size of a module and the offset of the first module in an image whose width and height are `size`, with at least `quiet_zone` modules of margin.
+#[inline]
+pub(crate) fn layout(
+    qr: &QrCode,
+    size: usize,
+    quiet_zone: usize,
+) -> Result<(usize, usize), QRCodeError> {
+    let margin_size = quiet_zone;
+
+    let data_length = qr.size() as usize;
+
+    let data_length_with_margin = data_length + 2 * margin_size;
+
+    let point_size = size / data_length_with_margin;
+
+    if point_size == 0 {
+        return Err(QRCodeError::ImageSizeTooSmall);
+    }
+
+    let margin = (size - (point_size * data_length)) / 2;
+
+    Ok((point_size, margin))
+}
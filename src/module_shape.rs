@@ -0,0 +1,66 @@
+use std::io::{self, Write};
+
+#[cfg(any(feature = "image", feature = "tiff"))]
+use crate::border::inside_rounded_square;
+
+/// Shapes of dark modules in the data area. Finder, timing and alignment patterns always stay square for reliable scanning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModuleShape {
+    #[default]
+    Square,
+    /// A square whose corners are rounded with a quarter of the module size.
+    Rounded,
+    /// A circle touching the edges of the module.
+    Dot,
+}
+
+impl ModuleShape {
+    /// Check whether a point, relative to the top-left corner of a module of the size `size`, is covered by the shape shrunk around its center by `gap`, a fraction of the size.
+    #[cfg(any(feature = "image", feature = "tiff"))]
+    pub(crate) fn covers(self, size: f64, gap: f64, x: f64, y: f64) -> bool {
+        let inset = size * gap / 2.0;
+
+        let (size, x, y) = (size - 2.0 * inset, x - inset, y - inset);
+
+        match self {
+            ModuleShape::Square => x >= 0.0 && y >= 0.0 && x < size && y < size,
+            ModuleShape::Rounded => inside_rounded_square(0.0, size, size / 4.0, x, y),
+            ModuleShape::Dot => {
+                let r = size / 2.0;
+
+                (x - r).powi(2) + (y - r).powi(2) <= r.powi(2)
+            },
+        }
+    }
+
+    /// Write the SVG path data of a module whose top-left corner is `(x, y)`.
+    pub(crate) fn write_svg_path<W: Write>(
+        self,
+        writer: &mut W,
+        x: f64,
+        y: f64,
+        size: f64,
+    ) -> Result<(), io::Error> {
+        match self {
+            ModuleShape::Square => writer.write_fmt(format_args!("M{x} {y}h{size}v{size}H{x}V{y}")),
+            ModuleShape::Rounded => {
+                let r = size / 4.0;
+                let edge = size - 2.0 * r;
+                let start = x + r;
+
+                writer.write_fmt(format_args!(
+                    "M{start} {y}h{edge}a{r} {r} 0 0 1 {r} {r}v{edge}a{r} {r} 0 0 1 -{r} \
+                     {r}h-{edge}a{r} {r} 0 0 1 -{r} -{r}v-{edge}a{r} {r} 0 0 1 {r} -{r}z"
+                ))
+            },
+            ModuleShape::Dot => {
+                let r = size / 2.0;
+                let cy = y + r;
+
+                writer.write_fmt(format_args!(
+                    "M{x} {cy}a{r} {r} 0 1 0 {size} 0a{r} {r} 0 1 0 -{size} 0z"
+                ))
+            },
+        }
+    }
+}
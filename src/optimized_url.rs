@@ -0,0 +1,187 @@
+use std::fmt::{self, Debug, Formatter};
+
+use crate::{
+    classify::{self, make_segments},
+    generate_qrcode_from_segments, QRCodeError, QrCodeEcc, QrSegment,
+};
+
+/// A URL rewritten to be encoded in alphanumeric mode as much as possible, with the version it saves.
+#[derive(Clone)]
+pub struct OptimizedUrl {
+    url:              String,
+    segments:         Vec<QrSegment>,
+    original_version: u8,
+    version:          u8,
+}
+
+impl OptimizedUrl {
+    /// The rewritten URL. It is equivalent to the original URL.
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The segments to encode with the `_from_segments` functions.
+    #[inline]
+    pub fn segments(&self) -> &[QrSegment] {
+        &self.segments
+    }
+
+    /// Whether the whole URL can be encoded in alphanumeric mode. Otherwise, the path, the query or the fragment contains lowercase letters or other characters outside the alphanumeric character set.
+    #[inline]
+    pub fn is_alphanumeric(&self) -> bool {
+        QrSegment::is_alphanumeric(&self.url)
+    }
+
+    /// The version (1 to 40) of the QR code encoding the original URL.
+    #[inline]
+    pub fn original_version(&self) -> u8 {
+        self.original_version
+    }
+
+    /// The version (1 to 40) of the QR code encoding the segments.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// How many versions are saved by the optimization.
+    #[inline]
+    pub fn versions_saved(&self) -> u8 {
+        self.original_version - self.version
+    }
+}
+
+impl Debug for OptimizedUrl {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        f.debug_struct("OptimizedUrl")
+            .field("url", &self.url)
+            .field("original_version", &self.original_version)
+            .field("version", &self.version)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Uppercase the case-insensitive parts of a URL (the scheme, the host and percent-encoded octets) so that it can be encoded in the compact alphanumeric mode. The path, the query and the fragment are kept as they are, and the leading alphanumeric part is split into its own segment if that makes the QR code smaller.
+///
+/// The URL needs a scheme followed by `://` and a host, no whitespace or control characters, and two hexadecimal digits after every `%`. Otherwise, `QRCodeError::InvalidInput` is returned.
+pub fn optimize_url<S: AsRef<str>>(url: S, ecc: QrCodeEcc) -> Result<OptimizedUrl, QRCodeError> {
+    let original = url.as_ref();
+
+    let url = uppercase_case_insensitive_parts(original)?;
+
+    let original_version =
+        generate_qrcode_from_segments(&make_segments(original.as_bytes()), ecc)?.version().value();
+
+    let mut segments = make_segments(url.as_bytes());
+    let mut version = generate_qrcode_from_segments(&segments, ecc)?.version().value();
+
+    // alphanumeric characters are ASCII, so the prefix ends on a character boundary
+    let prefix_len = url.bytes().position(|b| !classify::is_alphanumeric(b)).unwrap_or(url.len());
+
+    if prefix_len > 0 && prefix_len < url.len() {
+        let mixed_segments = vec![
+            QrSegment::make_alphanumeric(&url[..prefix_len]),
+            QrSegment::make_bytes(&url.as_bytes()[prefix_len..]),
+        ];
+
+        let mixed_version = generate_qrcode_from_segments(&mixed_segments, ecc)?.version().value();
+
+        if mixed_version < version {
+            segments = mixed_segments;
+            version = mixed_version;
+        }
+    }
+
+    // never make it worse
+    if version > original_version {
+        return Ok(OptimizedUrl {
+            url: original.to_string(),
+            segments: make_segments(original.as_bytes()),
+            original_version,
+            version: original_version,
+        });
+    }
+
+    Ok(OptimizedUrl {
+        url,
+        segments,
+        original_version,
+        version,
+    })
+}
+
+/// Check whether `scheme` is a scheme of URLs, i.e. a letter followed by letters, digits, `+`, `-` and `.`.
+#[inline]
+fn is_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+fn uppercase_case_insensitive_parts(url: &str) -> Result<String, QRCodeError> {
+    if url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(QRCodeError::invalid_input(
+            "url",
+            "must not contain whitespace or control characters",
+        ));
+    }
+
+    let index = url.find("://").filter(|&index| is_scheme(&url[..index])).ok_or_else(|| {
+        QRCodeError::invalid_input("url", "must start with a scheme followed by `://`")
+    })?;
+
+    let mut result = String::with_capacity(url.len());
+
+    let (scheme, rest) = url.split_at(index + 3);
+
+    result.push_str(&scheme.to_ascii_uppercase());
+
+    // the authority ends at the first `/`, `?` or `#` after `://`
+    let authority_len = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+
+    let (authority, rest) = rest.split_at(authority_len);
+
+    // the user information is case-sensitive
+    let host_start = authority.rfind('@').map_or(0, |index| index + 1);
+
+    let host_port = &authority[host_start..];
+
+    // the port follows the last `:` which is not in an IPv6 address
+    let host_len = match host_port.rfind(':') {
+        Some(index) if !host_port[index..].contains(']') => index,
+        _ => host_port.len(),
+    };
+
+    if host_len == 0 {
+        return Err(QRCodeError::invalid_input("url", "must have a host"));
+    }
+
+    result.push_str(&authority[..host_start]);
+    result.push_str(&host_port.to_ascii_uppercase());
+
+    let mut chars = rest.chars();
+
+    while let Some(c) = chars.next() {
+        result.push(c);
+
+        if c == '%' {
+            let hex: String = chars.clone().take(2).collect();
+
+            if hex.len() != 2 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(QRCodeError::invalid_input(
+                    "url",
+                    "`%` must be followed by two hexadecimal digits",
+                ));
+            }
+
+            result.push_str(&hex.to_ascii_uppercase());
+
+            chars.nth(1);
+        }
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,98 @@
+use std::path::Path;
+
+/// Image formats which can be rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    Svg,
+    #[cfg(feature = "image")]
+    Png,
+    /// A JPEG image with the quality 90 and no metadata. Use `to_jpeg_to_vec` to choose the quality and embed EXIF metadata.
+    #[cfg(feature = "jpeg")]
+    Jpeg,
+    /// A single-page PDF document painted with CMYK inks. The size is in points (1/72 inch).
+    Pdf,
+    /// A CMYK TIFF image painted with CMYK inks.
+    #[cfg(feature = "tiff")]
+    Tiff,
+    /// An HTML snippet of a single `div` which draws every module with a CSS `box-shadow`, for environments which strip `<svg>` and `<img>` tags. Only the colors in the options are used.
+    BoxShadowHtml,
+}
+
+impl OutputFormat {
+    /// Get the format of a file extension (case-insensitive), e.g. `svg`, `png`, `jpg`, `jpeg`, `pdf`, `tif`, `tiff`, `html` or `htm`. Formats whose features are disabled are not recognized.
+    pub fn from_extension<S: AsRef<str>>(extension: S) -> Option<Self> {
+        let extension = extension.as_ref();
+
+        if extension.eq_ignore_ascii_case("svg") {
+            return Some(OutputFormat::Svg);
+        }
+
+        #[cfg(feature = "image")]
+        if extension.eq_ignore_ascii_case("png") {
+            return Some(OutputFormat::Png);
+        }
+
+        #[cfg(feature = "jpeg")]
+        if extension.eq_ignore_ascii_case("jpg") || extension.eq_ignore_ascii_case("jpeg") {
+            return Some(OutputFormat::Jpeg);
+        }
+
+        if extension.eq_ignore_ascii_case("pdf") {
+            return Some(OutputFormat::Pdf);
+        }
+
+        #[cfg(feature = "tiff")]
+        if extension.eq_ignore_ascii_case("tif") || extension.eq_ignore_ascii_case("tiff") {
+            return Some(OutputFormat::Tiff);
+        }
+
+        if extension.eq_ignore_ascii_case("html") || extension.eq_ignore_ascii_case("htm") {
+            return Some(OutputFormat::BoxShadowHtml);
+        }
+
+        None
+    }
+
+    /// Get the conventional file extension of the format, without the leading dot.
+    #[inline]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Svg => "svg",
+            #[cfg(feature = "image")]
+            OutputFormat::Png => "png",
+            #[cfg(feature = "jpeg")]
+            OutputFormat::Jpeg => "jpg",
+            OutputFormat::Pdf => "pdf",
+            #[cfg(feature = "tiff")]
+            OutputFormat::Tiff => "tiff",
+            OutputFormat::BoxShadowHtml => "html",
+        }
+    }
+
+    /// Get the format of a file path by its extension.
+    #[inline]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        path.as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(Self::from_extension)
+    }
+}
+
+/// The format and the size of a rendered image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutputSpec {
+    pub format: OutputFormat,
+    pub size:   usize,
+}
+
+impl OutputSpec {
+    #[inline]
+    pub const fn new(format: OutputFormat, size: usize) -> Self {
+        OutputSpec {
+            format,
+            size,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(any(feature = "image", feature = "tiff"))]
+use crate::QRCodeError;
+
+/// Limits of the memory needed by raster outputs, checked before anything is allocated, so that servers which take the size from their users cannot be made to allocate huge images. Vector outputs are not limited, since their lengths do not grow with the size.
+///
+/// The limits are checked by the renderers which take `RenderOptions`, such as `render`, and by `ContactSheet` and `LabelLayout`. The `to_image*`, `to_png*`, `to_scanlines` and `to_apng*` functions have no limits and only reject sizes whose pixels cannot be counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OutputLimit {
+    pub(crate) max_pixels: Option<u64>,
+    pub(crate) max_bytes:  Option<u64>,
+}
+
+impl OutputLimit {
+    /// No limits.
+    pub const UNLIMITED: OutputLimit = OutputLimit::new();
+
+    /// Create a limit without any limits.
+    #[inline]
+    pub const fn new() -> Self {
+        OutputLimit {
+            max_pixels: None, max_bytes: None
+        }
+    }
+
+    /// Set the maximum number of pixels (the width × the height) of an image.
+    #[inline]
+    pub const fn max_pixels(mut self, max_pixels: Option<u64>) -> Self {
+        self.max_pixels = max_pixels;
+
+        self
+    }
+
+    /// Set the maximum number of bytes of the pixel buffers allocated to render an image.
+    #[inline]
+    pub const fn max_bytes(mut self, max_bytes: Option<u64>) -> Self {
+        self.max_bytes = max_bytes;
+
+        self
+    }
+
+    /// Check a `size` × `size` image whose pixels take `bytes_per_pixel` bytes of buffers.
+    #[cfg(any(feature = "image", feature = "tiff"))]
+    #[inline]
+    pub(crate) fn check(self, size: usize, bytes_per_pixel: u64) -> Result<(), QRCodeError> {
+        self.check_dimensions(size, size, bytes_per_pixel)
+    }
+
+    /// Check a `width` × `height` image whose pixels take `bytes_per_pixel` bytes of buffers.
+    #[cfg(any(feature = "image", feature = "tiff"))]
+    pub(crate) fn check_dimensions(
+        self,
+        width: usize,
+        height: usize,
+        bytes_per_pixel: u64,
+    ) -> Result<(), QRCodeError> {
+        let pixels = (width as u64).saturating_mul(height as u64);
+
+        if let Some(max) = self.max_pixels {
+            if pixels > max {
+                return Err(QRCodeError::OutputLimitExceeded {
+                    limit: "pixels",
+                    requested: pixels,
+                    max,
+                });
+            }
+        }
+
+        if let Some(max) = self.max_bytes {
+            let bytes = pixels.saturating_mul(bytes_per_pixel);
+
+            if bytes > max {
+                return Err(QRCodeError::OutputLimitExceeded {
+                    limit: "bytes",
+                    requested: bytes,
+                    max,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+/// Preset options for common targets, see `RenderOptions::from_profile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputProfile {
+    /// High error correction and a 4-module quiet zone. PNG images are tagged with 300 DPI.
+    Print,
+    /// Medium error correction and transparent light modules.
+    Web,
+    /// Medium error correction and 1-bit PNG images for thermal printers.
+    Thermal,
+}
@@ -0,0 +1,27 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when parsing an error correction level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseEccError {
+    InvalidName,
+    OutOfRange(u8),
+}
+
+impl Display for ParseEccError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            ParseEccError::InvalidName => f.write_str(
+                "the error correction level should be L, M, Q, H, Low, Medium, Quartile or High",
+            ),
+            ParseEccError::OutOfRange(n) => f.write_fmt(format_args!(
+                "the error correction level should be in the range 0 to 3, but got {n}"
+            )),
+        }
+    }
+}
+
+impl Error for ParseEccError {}
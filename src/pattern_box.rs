@@ -0,0 +1,113 @@
+use qrcodegen::{QrCode, QrSegment};
+
+use crate::{
+    classify::make_segments, generate_qrcode_advanced, layout, payload_segments,
+    zone::alignment_pattern_positions, IntoQrPayload, QRCodeError, Rect, RenderOptions, Zone,
+};
+
+/// The bounding box of a part of a QR code, in modules and in pixels of the `size` × `size` image. In SVG images with bleed, the pixels are offset by the slug.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatternBox {
+    /// `Zone::Data` is the whole symbol, which the other parts are inside.
+    pub zone:    Zone,
+    pub modules: Rect,
+    pub pixels:  Rect,
+}
+
+fn pattern_boxes_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<PatternBox>, QRCodeError> {
+    let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+    let s = qr.size() as usize;
+
+    let pattern_box = |zone: Zone, x: usize, y: usize, width: usize, height: usize| PatternBox {
+        zone,
+        modules: Rect::new(x, y, width, height),
+        pixels: Rect::new(
+            x * point_size + margin,
+            y * point_size + margin,
+            width * point_size,
+            height * point_size,
+        ),
+    };
+
+    let mut boxes = vec![
+        pattern_box(Zone::Data, 0, 0, s, s),
+        pattern_box(Zone::Finder, 0, 0, 7, 7),
+        pattern_box(Zone::Finder, s - 7, 0, 7, 7),
+        pattern_box(Zone::Finder, 0, s - 7, 7, 7),
+        pattern_box(Zone::Timing, 8, 6, s - 16, 1),
+        pattern_box(Zone::Timing, 6, 8, 1, s - 16),
+    ];
+
+    let positions = alignment_pattern_positions(qr);
+    let last = positions.len().saturating_sub(1);
+
+    for (j, &y) in positions.iter().enumerate() {
+        for (i, &x) in positions.iter().enumerate() {
+            // the three corners are occupied by the finder patterns
+            if (i == 0 && (j == 0 || j == last)) || (i == last && j == 0) {
+                continue;
+            }
+
+            boxes.push(pattern_box(Zone::Alignment, x as usize - 2, y as usize - 2, 5, 5));
+        }
+    }
+
+    Ok(boxes)
+}
+
+/// Get the bounding boxes of the finder patterns, the timing patterns, the alignment patterns and the whole symbol of data rendered with options at `size`, so that overlays can avoid covering them.
+#[inline]
+pub fn pattern_boxes<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<PatternBox>, QRCodeError> {
+    pattern_boxes_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Get the bounding boxes of the finder patterns, the timing patterns, the alignment patterns and the whole symbol of text rendered with options at `size`, so that overlays can avoid covering them.
+#[inline]
+pub fn pattern_boxes_from_str<S: AsRef<str>>(
+    text: S,
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<PatternBox>, QRCodeError> {
+    pattern_boxes_inner(
+        &generate_qrcode_advanced(
+            &make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        size,
+    )
+}
+
+/// Get the bounding boxes of the finder patterns, the timing patterns, the alignment patterns and the whole symbol of segments rendered with options at `size`, so that overlays can avoid covering them.
+#[inline]
+pub fn pattern_boxes_from_segments(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    size: usize,
+) -> Result<Vec<PatternBox>, QRCodeError> {
+    pattern_boxes_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        size,
+    )
+}
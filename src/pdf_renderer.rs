@@ -0,0 +1,269 @@
+use std::io::{self, Write};
+
+use qrcodegen::QrCode;
+
+use crate::{
+    layout,
+    print_marks::{crop_mark_lines, registration_crosshair, registration_marks, MARK_STROKE_WIDTH},
+    CmykColor, QRCodeError, RenderOptions, SpotColor,
+};
+
+/// The magic number to approximate a quarter circle with a cubic Bézier curve.
+const KAPPA: f64 = 0.5522847498;
+
+fn write_ink(content: &mut Vec<u8>, ink: CmykColor) -> Result<(), io::Error> {
+    let CmykColor {
+        c,
+        m,
+        y,
+        k,
+    } = ink;
+
+    content.write_fmt(format_args!(
+        "{} {} {} {} k\n",
+        f64::from(c) / 100.0,
+        f64::from(m) / 100.0,
+        f64::from(y) / 100.0,
+        f64::from(k) / 100.0
+    ))
+}
+
+/// Write a PDF name object, escaping the characters which are not regular characters.
+fn write_name(pdf: &mut Vec<u8>, name: &str) -> Result<(), io::Error> {
+    pdf.push(b'/');
+
+    for b in name.bytes() {
+        match b {
+            b'!'..=b'~'
+                if !matches!(
+                    b,
+                    b'#' | b'%' | b'(' | b')' | b'/' | b'<' | b'>' | b'[' | b']' | b'{' | b'}'
+                ) =>
+            {
+                pdf.push(b)
+            },
+            _ => pdf.write_fmt(format_args!("#{b:02X}"))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a Separation color space whose tint transform maps the tint linearly to the alternate CMYK color.
+fn write_separation(pdf: &mut Vec<u8>, spot_color: &SpotColor) -> Result<(), io::Error> {
+    let CmykColor {
+        c,
+        m,
+        y,
+        k,
+    } = spot_color.alternate;
+
+    pdf.extend_from_slice(b"[/Separation ");
+    write_name(pdf, &spot_color.name)?;
+    pdf.write_fmt(format_args!(
+        " /DeviceCMYK << /FunctionType 2 /Domain [0 1] /C0 [0 0 0 0] /C1 [{} {} {} {}] /N 1 >>]",
+        f64::from(c) / 100.0,
+        f64::from(m) / 100.0,
+        f64::from(y) / 100.0,
+        f64::from(k) / 100.0
+    ))
+}
+
+/// Render a QR code to a single-page PDF document whose trimmed page is `size` × `size` points, painted with CMYK inks.
+pub(crate) fn to_pdf_inner<W: Write>(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    mut writer: W,
+) -> Result<(), QRCodeError> {
+    let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+    let s = qr.size();
+
+    let bleed = options.bleed;
+    let slug = options.slug();
+
+    let page_size = size + 2 * slug;
+
+    let mut content = Vec::with_capacity(4096);
+
+    if slug > 0 {
+        content.write_fmt(format_args!("1 0 0 1 {slug} {slug} cm\n"))?;
+    }
+
+    // the dark paint is the spot color if there is one
+    let write_paint = |content: &mut Vec<u8>, dark: bool| -> Result<(), io::Error> {
+        match options.dark_spot_color.as_ref() {
+            Some(spot_color) if dark => content
+                .write_fmt(format_args!("/CS0 cs {} scn\n", f64::from(spot_color.tint) / 100.0)),
+            _ => write_ink(content, if dark { options.dark_ink } else { options.light_ink }),
+        }
+    };
+
+    match options.quiet_zone_ink {
+        Some(quiet_zone_ink) => write_ink(&mut content, quiet_zone_ink)?,
+        None => write_paint(&mut content, options.inverted)?,
+    }
+
+    if bleed > 0 {
+        let background_size = size + 2 * bleed;
+
+        content.write_fmt(format_args!(
+            "-{bleed} -{bleed} {background_size} {background_size} re f\n"
+        ))?;
+    } else {
+        content.write_fmt(format_args!("0 0 {size} {size} re f\n"))?;
+    }
+
+    if options.quiet_zone_ink.is_some() {
+        let data_size = s as usize * point_size;
+
+        write_paint(&mut content, options.inverted)?;
+
+        content.write_fmt(format_args!(
+            "{margin} {y} {data_size} {data_size} re f\n",
+            y = size - margin - data_size
+        ))?;
+    }
+
+    write_paint(&mut content, !options.inverted)?;
+
+    for i in 0..s {
+        // PDF has its origin at the bottom-left corner
+        let y = size - (i as usize * point_size + margin) - point_size;
+
+        let mut j = 0;
+
+        while j < s {
+            if qr.get_module(j, i) {
+                let start = j;
+
+                while j < s && qr.get_module(j, i) {
+                    j += 1;
+                }
+
+                let x = start as usize * point_size + margin;
+                let w = (j - start) as usize * point_size;
+
+                content.write_fmt(format_args!("{x} {y} {w} {point_size} re\n"))?;
+            } else {
+                j += 1;
+            }
+        }
+    }
+
+    content.extend_from_slice(b"f\n");
+
+    if options.crop_marks {
+        let sf = size as f64;
+
+        // registration color, which appears on every separation
+        content.write_fmt(format_args!("1 1 1 1 K\n{MARK_STROKE_WIDTH} w\n"))?;
+
+        let (centers, radius) = registration_marks(size, bleed);
+
+        let lines = crop_mark_lines(size, bleed)
+            .into_iter()
+            .chain(centers.into_iter().flat_map(|center| registration_crosshair(center, radius)));
+
+        for (x1, y1, x2, y2) in lines {
+            content.write_fmt(format_args!("{x1} {} m {x2} {} l\n", sf - y1, sf - y2))?;
+        }
+
+        for (cx, cy) in centers {
+            let cy = sf - cy;
+            let r = radius;
+            let k = radius * KAPPA;
+
+            content.write_fmt(format_args!(
+                "{} {cy} m {} {} {} {} {cx} {} c {} {} {} {} {} {cy} c {} {} {} {} {cx} {} c {} \
+                 {} {} {} {} {cy} c\n",
+                cx + r,
+                cx + r,
+                cy + k,
+                cx + k,
+                cy + r,
+                cy + r,
+                cx - k,
+                cy + r,
+                cx - r,
+                cy + k,
+                cx - r,
+                cx - r,
+                cy - k,
+                cx - k,
+                cy - r,
+                cy - r,
+                cx + k,
+                cy - r,
+                cx + r,
+                cy - k,
+                cx + r,
+            ))?;
+        }
+
+        content.extend_from_slice(b"S\n");
+    }
+
+    let mut pdf = Vec::with_capacity(content.len() + 1024);
+
+    let mut offsets = [0usize; 4];
+
+    pdf.extend_from_slice(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n");
+
+    offsets[0] = pdf.len();
+    pdf.extend_from_slice(b"1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n");
+
+    offsets[1] = pdf.len();
+    pdf.extend_from_slice(b"2 0 obj\n<< /Type /Pages /Kids [3 0 R] /Count 1 >>\nendobj\n");
+
+    offsets[2] = pdf.len();
+    pdf.write_fmt(format_args!(
+        "3 0 obj\n<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {page_size} {page_size}]"
+    ))?;
+
+    if slug > 0 {
+        let trim_end = slug + size;
+        let bleed_start = slug - bleed;
+        let bleed_end = trim_end + bleed;
+
+        pdf.write_fmt(format_args!(
+            " /BleedBox [{bleed_start} {bleed_start} {bleed_end} {bleed_end}] /TrimBox [{slug} \
+             {slug} {trim_end} {trim_end}]"
+        ))?;
+    }
+
+    match options.dark_spot_color.as_ref() {
+        Some(spot_color) => {
+            pdf.extend_from_slice(b" /Resources << /ColorSpace << /CS0 ");
+            write_separation(&mut pdf, spot_color)?;
+            pdf.extend_from_slice(b" >> >>");
+        },
+        None => pdf.extend_from_slice(b" /Resources << >>"),
+    }
+
+    pdf.extend_from_slice(b" /Contents 4 0 R >>\nendobj\n");
+
+    offsets[3] = pdf.len();
+    pdf.write_fmt(format_args!("4 0 obj\n<< /Length {} >>\nstream\n", content.len()))?;
+    pdf.extend_from_slice(&content);
+    pdf.extend_from_slice(b"endstream\nendobj\n");
+
+    let xref_offset = pdf.len();
+
+    pdf.extend_from_slice(b"xref\n0 5\n0000000000 65535 f \n");
+
+    for offset in offsets {
+        pdf.write_fmt(format_args!("{offset:010} 00000 n \n"))?;
+    }
+
+    pdf.write_fmt(format_args!(
+        "trailer\n<< /Size 5 /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n"
+    ))?;
+
+    writer.write_all(&pdf)?;
+
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::Color;
+
+/// The byte order of multi-byte pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ByteOrder {
+    /// The low byte first, which most microcontrollers use in memory.
+    #[default]
+    LittleEndian,
+    /// The high byte first, which most SPI TFT controllers (e.g. ILI9341 and ST7789) expect on the wire.
+    BigEndian,
+}
+
+/// The layout of pixels in raw images, so that display drivers can copy buffers directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PixelFormat {
+    /// One byte of luminance per pixel, the same as `to_image` makes.
+    #[default]
+    Luma8,
+    /// 16 bits per pixel, with 5 bits of red in the high bits, 6 bits of green and 5 bits of blue.
+    Rgb565(ByteOrder),
+    /// 16 bits per pixel, with 5 bits of blue in the high bits, 6 bits of green and 5 bits of red.
+    Bgr565(ByteOrder),
+    /// Three bytes per pixel, in the order of red, green and blue.
+    Rgb888,
+    /// Three bytes per pixel, in the order of blue, green and red.
+    Bgr888,
+}
+
+impl PixelFormat {
+    /// The number of bytes of a pixel.
+    #[inline]
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            PixelFormat::Luma8 => 1,
+            PixelFormat::Rgb565(_) | PixelFormat::Bgr565(_) => 2,
+            PixelFormat::Rgb888 | PixelFormat::Bgr888 => 3,
+        }
+    }
+
+    /// Encode a color to a pixel. The alpha channel is ignored. Only the first `bytes_per_pixel` bytes are used.
+    pub(crate) fn encode(self, color: Color) -> [u8; 3] {
+        let Color {
+            r,
+            g,
+            b,
+            ..
+        } = color;
+
+        let pack_565 = |high: u8, low: u8, byte_order: ByteOrder| {
+            let value =
+                (u16::from(high) >> 3) << 11 | (u16::from(g) >> 2) << 5 | u16::from(low) >> 3;
+
+            let [first, second] = match byte_order {
+                ByteOrder::LittleEndian => value.to_le_bytes(),
+                ByteOrder::BigEndian => value.to_be_bytes(),
+            };
+
+            [first, second, 0]
+        };
+
+        match self {
+            PixelFormat::Luma8 => [(color.luminance() * 255.0).round() as u8, 0, 0],
+            PixelFormat::Rgb565(byte_order) => pack_565(r, b, byte_order),
+            PixelFormat::Bgr565(byte_order) => pack_565(b, r, byte_order),
+            PixelFormat::Rgb888 => [r, g, b],
+            PixelFormat::Bgr888 => [b, g, r],
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    hash::{Hash, Hasher},
+    iter::{once, Once},
+};
+
+use plotters::{
+    element::{Drawable, PointCollection},
+    style::{BLACK, WHITE},
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use qrcodegen::QrCode;
+
+use crate::{
+    content_hash::content_hash_inner, generate_qrcode, generate_qrcode_from_segments,
+    generate_qrcode_from_str, IntoQrPayload, QRCodeError, QrCodeEcc, QrSegment,
+};
+
+/// A QR code which can be drawn by plotters. The anchor coordinate is the upper-left corner of the image.
+#[derive(Clone, PartialEq, Eq)]
+pub struct QrCodeElement<Coord> {
+    coord: Coord,
+    qr:    QrCode,
+    size:  u32,
+}
+
+impl<Coord> QrCodeElement<Coord> {
+    /// Encode data to a QR code element whose image is `size` × `size` pixels.
+    #[inline]
+    pub fn new<D: IntoQrPayload>(
+        data: D,
+        ecc: QrCodeEcc,
+        coord: Coord,
+        size: u32,
+    ) -> Result<Self, QRCodeError> {
+        Self::from_qrcode(generate_qrcode(data, ecc)?, coord, size)
+    }
+
+    /// Encode text to a QR code element whose image is `size` × `size` pixels.
+    #[inline]
+    pub fn from_str<S: AsRef<str>>(
+        text: S,
+        ecc: QrCodeEcc,
+        coord: Coord,
+        size: u32,
+    ) -> Result<Self, QRCodeError> {
+        Self::from_qrcode(generate_qrcode_from_str(text, ecc)?, coord, size)
+    }
+
+    /// Encode segments to a QR code element whose image is `size` × `size` pixels.
+    #[inline]
+    pub fn from_segments(
+        segments: &[QrSegment],
+        ecc: QrCodeEcc,
+        coord: Coord,
+        size: u32,
+    ) -> Result<Self, QRCodeError> {
+        Self::from_qrcode(generate_qrcode_from_segments(segments, ecc)?, coord, size)
+    }
+
+    fn from_qrcode(qr: QrCode, coord: Coord, size: u32) -> Result<Self, QRCodeError> {
+        let margin_size = 1;
+
+        let data_length_with_margin = qr.size() as u32 + 2 * margin_size;
+
+        if size / data_length_with_margin == 0 {
+            return Err(QRCodeError::ImageSizeTooSmall);
+        }
+
+        Ok(QrCodeElement {
+            coord,
+            qr,
+            size,
+        })
+    }
+}
+
+impl<Coord: Hash> Hash for QrCodeElement<Coord> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coord.hash(state);
+        content_hash_inner(&self.qr).hash(state);
+        self.size.hash(state);
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a QrCodeElement<Coord> {
+    type IntoIter = Once<&'a Coord>;
+    type Point = &'a Coord;
+
+    #[inline]
+    fn point_iter(self) -> Self::IntoIter {
+        once(&self.coord)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for QrCodeElement<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut pos: I,
+        backend: &mut DB,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (left, top) = match pos.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+
+        let margin_size = 1;
+
+        let s = self.qr.size();
+
+        let data_length = s as u32;
+
+        let data_length_with_margin = data_length + 2 * margin_size;
+
+        let point_size = self.size / data_length_with_margin;
+
+        let margin = (self.size - (point_size * data_length)) / 2;
+
+        let size = self.size as i32;
+
+        backend.draw_rect((left, top), (left + size - 1, top + size - 1), &WHITE, true)?;
+
+        for i in 0..s {
+            for j in 0..s {
+                if self.qr.get_module(j, i) {
+                    let x = left + (j as u32 * point_size + margin) as i32;
+                    let y = top + (i as u32 * point_size + margin) as i32;
+
+                    backend.draw_rect(
+                        (x, y),
+                        (x + point_size as i32 - 1, y + point_size as i32 - 1),
+                        &BLACK,
+                        true,
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::crc32::crc32;
+
+/// The length of the PNG signature plus the IHDR chunk.
+const IHDR_END: usize = 8 + 4 + 4 + 13 + 4;
+
+/// Insert an ancillary chunk right after the IHDR chunk of an encoded PNG image.
+pub(crate) fn insert_chunk_after_ihdr(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    let mut chunk = Vec::with_capacity(data.len() + 12);
+
+    chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(chunk_type);
+    chunk.extend_from_slice(data);
+
+    let crc = crc32(&chunk[4..]);
+
+    chunk.extend_from_slice(&crc.to_be_bytes());
+
+    png.splice(IHDR_END..IHDR_END, chunk);
+}
+
+/// Convert dots per inch to pixels per meter.
+#[inline]
+pub(crate) fn dpi_to_ppm(dpi: u32) -> u32 {
+    (f64::from(dpi) / 0.0254).round() as u32
+}
+
+/// Create the data of a pHYs chunk.
+pub(crate) fn phys_data(dpi: u32) -> [u8; 9] {
+    let ppm = dpi_to_ppm(dpi);
+
+    let mut data = [1u8; 9];
+
+    data[..4].copy_from_slice(&ppm.to_be_bytes());
+    data[4..8].copy_from_slice(&ppm.to_be_bytes());
+
+    data
+}
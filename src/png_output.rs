@@ -0,0 +1,351 @@
+use std::{
+    io::{Seek, Write},
+    path::Path,
+};
+
+use image::{
+    codecs::png::{CompressionType, FilterType, PngEncoder},
+    ColorType, ImageBuffer, ImageEncoder, ImageFormat, Luma,
+};
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str, png_chunk,
+    to_image_inner, trace, FileOptions, IntoQrPayload, OutputFormat, OutputSpec, QRCodeError,
+};
+
+/// The minimum module size (in millimeters) which is accepted by the `to_png_physical*` functions.
+pub const MIN_PRINTABLE_MODULE_SIZE_MM: f64 = 0.25;
+
+const MM_PER_INCH: f64 = 25.4;
+
+#[inline]
+fn to_png_inner<W: Write>(qr: QrCode, size: usize, writer: W) -> Result<(), QRCodeError> {
+    trace::render_to_writer(OutputSpec::new(OutputFormat::Png, size), writer, |writer| {
+        let img_raw = to_image_inner(qr, size)?;
+
+        let encoder =
+            PngEncoder::new_with_quality(writer, CompressionType::Best, FilterType::NoFilter);
+
+        Ok(encoder.write_image(&img_raw, size as u32, size as u32, ColorType::L8.into())?)
+    })
+}
+
+#[inline]
+fn to_png_to_vec_inner(qr: QrCode, size: usize) -> Result<Vec<u8>, QRCodeError> {
+    let mut png = Vec::with_capacity(4096);
+
+    to_png_inner(qr, size, &mut png)?;
+
+    Ok(png)
+}
+
+#[inline]
+fn to_png_to_file_inner<P: AsRef<Path>>(
+    qr: QrCode,
+    size: usize,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    options.write(path, |file| to_png_inner(qr, size, file))
+}
+
+#[inline]
+fn to_encoded_image_inner<W: Write + Seek>(
+    qr: QrCode,
+    size: usize,
+    format: ImageFormat,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    let img_raw = to_image_inner(qr, size)?;
+
+    Ok(image::write_buffer_with_format(
+        writer,
+        &img_raw,
+        size as u32,
+        size as u32,
+        ColorType::L8,
+        format,
+    )?)
+}
+
+fn to_png_physical_inner(qr: QrCode, size_mm: f64, dpi: u32) -> Result<Vec<u8>, QRCodeError> {
+    let size = (size_mm / MM_PER_INCH * f64::from(dpi)).round();
+
+    if size.is_nan() || size < 1.0 {
+        return Err(QRCodeError::ImageSizeTooSmall);
+    }
+
+    if size > usize::MAX as f64 {
+        return Err(QRCodeError::ImageSizeTooLarge);
+    }
+
+    let size = size as usize;
+
+    let margin_size = 1;
+
+    let data_length_with_margin = qr.size() as usize + 2 * margin_size;
+
+    let point_size = size / data_length_with_margin;
+
+    if point_size == 0 {
+        return Err(QRCodeError::ImageSizeTooSmall);
+    }
+
+    if point_size as f64 * MM_PER_INCH / f64::from(dpi) < MIN_PRINTABLE_MODULE_SIZE_MM {
+        return Err(QRCodeError::ModuleSizeTooSmall);
+    }
+
+    let mut png = to_png_to_vec_inner(qr, size)?;
+
+    png_chunk::insert_chunk_after_ihdr(&mut png, b"pHYs", &png_chunk::phys_data(dpi));
+
+    Ok(png)
+}
+
+#[inline]
+fn to_image_buffer_inner(
+    qr: QrCode,
+    size: usize,
+) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
+    let img_raw = to_image_inner(qr, size)?;
+
+    let img: ImageBuffer<Luma<u8>, Vec<u8>> =
+        ImageBuffer::from_vec(size as u32, size as u32, img_raw)
+            .ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+    Ok(img)
+}
+
+/// Encode data to a PNG image in memory.
+#[inline]
+pub fn to_png_to_vec<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_png_to_vec_inner(generate_qrcode(data, ecc)?, size)
+}
+
+/// Encode text to a PNG image in memory.
+#[inline]
+pub fn to_png_to_vec_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_png_to_vec_inner(generate_qrcode_from_str(text, ecc)?, size)
+}
+
+/// Encode segments to a PNG image in memory.
+#[inline]
+pub fn to_png_to_vec_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_png_to_vec_inner(generate_qrcode_from_segments(segments, ecc)?, size)
+}
+
+/// Encode data to a PNG image via a file path.
+#[inline]
+pub fn to_png_to_file<D: IntoQrPayload, P: AsRef<Path>>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_png_to_file_inner(generate_qrcode(data, ecc)?, size, path, FileOptions::new())
+}
+
+/// Encode text to a PNG image via a file path.
+#[inline]
+pub fn to_png_to_file_from_str<S: AsRef<str>, P: AsRef<Path>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_png_to_file_inner(generate_qrcode_from_str(text, ecc)?, size, path, FileOptions::new())
+}
+
+/// Encode text to a PNG image via a file path.
+#[inline]
+pub fn to_png_to_file_from_segments<P: AsRef<Path>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_png_to_file_inner(
+        generate_qrcode_from_segments(segments, ecc)?,
+        size,
+        path,
+        FileOptions::new(),
+    )
+}
+
+/// Encode data to a PNG image via a file path with options.
+#[inline]
+pub fn to_png_to_file_with_options<D: IntoQrPayload, P: AsRef<Path>>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    to_png_to_file_inner(generate_qrcode(data, ecc)?, size, path, options)
+}
+
+/// Encode text to a PNG image via a file path with options.
+#[inline]
+pub fn to_png_to_file_from_str_with_options<S: AsRef<str>, P: AsRef<Path>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    to_png_to_file_inner(generate_qrcode_from_str(text, ecc)?, size, path, options)
+}
+
+/// Encode segments to a PNG image via a file path with options.
+#[inline]
+pub fn to_png_to_file_from_segments_with_options<P: AsRef<Path>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    to_png_to_file_inner(generate_qrcode_from_segments(segments, ecc)?, size, path, options)
+}
+
+/// Encode data to a PNG image via a writer.
+#[inline]
+pub fn to_png_to_writer<D: IntoQrPayload, W: Write>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_png_inner(generate_qrcode(data, ecc)?, size, writer)
+}
+
+/// Encode text to a PNG image via a writer.
+#[inline]
+pub fn to_png_to_writer_from_str<S: AsRef<str>, W: Write>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_png_inner(generate_qrcode_from_str(text, ecc)?, size, writer)
+}
+
+/// Encode segments to a PNG image via a writer.
+#[inline]
+pub fn to_png_to_writer_from_segments<W: Write>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_png_inner(generate_qrcode_from_segments(segments, ecc)?, size, writer)
+}
+
+/// Encode data to an image of any format whose codec is enabled in the `image` crate via a writer. Unsupported formats fail with an `ImageError`.
+#[inline]
+pub fn to_encoded_image<D: IntoQrPayload, W: Write + Seek>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    format: ImageFormat,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_encoded_image_inner(generate_qrcode(data, ecc)?, size, format, writer)
+}
+
+/// Encode text to an image of any format whose codec is enabled in the `image` crate via a writer. Unsupported formats fail with an `ImageError`.
+#[inline]
+pub fn to_encoded_image_from_str<S: AsRef<str>, W: Write + Seek>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    format: ImageFormat,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_encoded_image_inner(generate_qrcode_from_str(text, ecc)?, size, format, writer)
+}
+
+/// Encode segments to an image of any format whose codec is enabled in the `image` crate via a writer. Unsupported formats fail with an `ImageError`.
+#[inline]
+pub fn to_encoded_image_from_segments<W: Write + Seek>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    format: ImageFormat,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_encoded_image_inner(generate_qrcode_from_segments(segments, ecc)?, size, format, writer)
+}
+
+/// Encode data to a PNG image in memory whose width and height are `size_mm` millimeters when printed at `dpi`. The DPI is embedded in the image.
+#[inline]
+pub fn to_png_physical<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size_mm: f64,
+    dpi: u32,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_png_physical_inner(generate_qrcode(data, ecc)?, size_mm, dpi)
+}
+
+/// Encode text to a PNG image in memory whose width and height are `size_mm` millimeters when printed at `dpi`. The DPI is embedded in the image.
+#[inline]
+pub fn to_png_physical_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size_mm: f64,
+    dpi: u32,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_png_physical_inner(generate_qrcode_from_str(text, ecc)?, size_mm, dpi)
+}
+
+/// Encode segments to a PNG image in memory whose width and height are `size_mm` millimeters when printed at `dpi`. The DPI is embedded in the image.
+#[inline]
+pub fn to_png_physical_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size_mm: f64,
+    dpi: u32,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_png_physical_inner(generate_qrcode_from_segments(segments, ecc)?, size_mm, dpi)
+}
+
+/// Encode data to a image buffer.
+pub fn to_image_buffer<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
+    to_image_buffer_inner(generate_qrcode(data, ecc)?, size)
+}
+
+/// Encode text to a image buffer.
+pub fn to_image_buffer_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
+    to_image_buffer_inner(generate_qrcode_from_str(text, ecc)?, size)
+}
+
+/// Encode segments to a image buffer.
+pub fn to_image_buffer_from_segments<S: AsRef<str>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<ImageBuffer<Luma<u8>, Vec<u8>>, QRCodeError> {
+    to_image_buffer_inner(generate_qrcode_from_segments(segments, ecc)?, size)
+}
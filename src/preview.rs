@@ -0,0 +1,84 @@
+use minifb::{Key, Window, WindowOptions};
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_advanced, generate_qrcode_from_segments,
+    generate_qrcode_from_str, payload_segments,
+    raster::{to_rgba, RGBA_BYTES_PER_PIXEL},
+    render_options::DEFAULT_RENDER_OPTIONS,
+    IntoQrPayload, QRCodeError, RenderOptions, Validate,
+};
+
+/// The width and height of the preview window in pixels.
+const PREVIEW_SIZE: usize = 512;
+
+fn preview_inner(qr: &QrCode, options: &RenderOptions) -> Result<(), QRCodeError> {
+    options.validate()?;
+
+    let rgba = to_rgba(qr, PREVIEW_SIZE, options, RGBA_BYTES_PER_PIXEL)?;
+
+    // minifb wants 0RGB pixels, so the transparency is blended over white
+    let buffer: Vec<u32> = rgba
+        .chunks_exact(4)
+        .map(|pixel| {
+            let a = u32::from(pixel[3]);
+
+            pixel[..3]
+                .iter()
+                .fold(0, |rgb, &c| (rgb << 8) | ((u32::from(c) * a + 255 * (255 - a)) / 255))
+        })
+        .collect();
+
+    let mut window = Window::new(
+        "QR Code Preview - Esc to close",
+        PREVIEW_SIZE,
+        PREVIEW_SIZE,
+        WindowOptions::default(),
+    )
+    .map_err(QRCodeError::WindowError)?;
+
+    window.set_target_fps(30);
+
+    while window.is_open() && !window.is_key_down(Key::Escape) {
+        window
+            .update_with_buffer(&buffer, PREVIEW_SIZE, PREVIEW_SIZE)
+            .map_err(QRCodeError::WindowError)?;
+    }
+
+    Ok(())
+}
+
+/// Encode data and show it in a window, blocking until the window is closed. This is meant for debugging.
+#[inline]
+pub fn preview<D: IntoQrPayload>(data: D, ecc: QrCodeEcc) -> Result<(), QRCodeError> {
+    preview_inner(&generate_qrcode(data, ecc)?, &DEFAULT_RENDER_OPTIONS)
+}
+
+/// Encode text and show it in a window, blocking until the window is closed. This is meant for debugging.
+#[inline]
+pub fn preview_from_str<S: AsRef<str>>(text: S, ecc: QrCodeEcc) -> Result<(), QRCodeError> {
+    preview_inner(&generate_qrcode_from_str(text, ecc)?, &DEFAULT_RENDER_OPTIONS)
+}
+
+/// Encode segments and show them in a window, blocking until the window is closed. This is meant for debugging.
+#[inline]
+pub fn preview_from_segments(segments: &[QrSegment], ecc: QrCodeEcc) -> Result<(), QRCodeError> {
+    preview_inner(&generate_qrcode_from_segments(segments, ecc)?, &DEFAULT_RENDER_OPTIONS)
+}
+
+/// Encode data with options and show it in a window, blocking until the window is closed. This is meant for debugging styling options.
+#[inline]
+pub fn preview_with_options<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+) -> Result<(), QRCodeError> {
+    preview_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+    )
+}
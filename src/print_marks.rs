@@ -0,0 +1,61 @@
+//! Geometry of the bleed area and the printer's marks around the trimmed image. Coordinates are relative to the upper-left corner of the trimmed image and grow rightward and downward.
+
+use crate::RenderOptions;
+
+/// The length of each crop mark.
+pub(crate) const CROP_MARK_LENGTH: usize = 18;
+
+/// The stroke width of crop marks and registration marks.
+pub(crate) const MARK_STROKE_WIDTH: f64 = 0.5;
+
+/// A line segment from `(x1, y1)` to `(x2, y2)`.
+pub(crate) type Line = (f64, f64, f64, f64);
+
+impl RenderOptions {
+    /// The distance between the edge of the trimmed image and the edge of the whole page.
+    #[inline]
+    pub(crate) fn slug(&self) -> usize {
+        if self.crop_marks {
+            self.bleed + CROP_MARK_LENGTH
+        } else {
+            self.bleed
+        }
+    }
+}
+
+/// Get the crop marks at the four corners. They start outside the bleed area so they are not covered by the bleeding background.
+pub(crate) fn crop_mark_lines(size: usize, bleed: usize) -> [Line; 8] {
+    let s = size as f64;
+    let b = bleed as f64;
+    let l = CROP_MARK_LENGTH as f64;
+
+    [
+        (-b - l, 0.0, -b, 0.0),
+        (0.0, -b - l, 0.0, -b),
+        (s + b, 0.0, s + b + l, 0.0),
+        (s, -b - l, s, -b),
+        (-b - l, s, -b, s),
+        (0.0, s + b, 0.0, s + b + l),
+        (s + b, s, s + b + l, s),
+        (s, s + b, s, s + b + l),
+    ]
+}
+
+/// Get the centers and the radius of the registration marks at the middle of the four sides.
+pub(crate) fn registration_marks(size: usize, bleed: usize) -> ([(f64, f64); 4], f64) {
+    let s = size as f64;
+    let b = bleed as f64;
+    let l = CROP_MARK_LENGTH as f64;
+
+    let d = b + l / 2.0;
+
+    ([(s / 2.0, -d), (s + d, s / 2.0), (s / 2.0, s + d), (-d, s / 2.0)], l / 4.0)
+}
+
+/// Get the crosshair lines of a registration mark.
+#[inline]
+pub(crate) fn registration_crosshair((cx, cy): (f64, f64), radius: f64) -> [Line; 2] {
+    let r = radius * 1.5;
+
+    [(cx - r, cy, cx + r, cy), (cx, cy - r, cx, cy + r)]
+}
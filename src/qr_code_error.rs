@@ -2,10 +2,15 @@ use std::{
     error::Error,
     fmt::{self, Display, Formatter},
     io,
+    path::{Path, PathBuf},
 };
 
 #[cfg(feature = "image")]
 use image::ImageError;
+#[cfg(feature = "tiff")]
+use tiff::TiffError;
+
+use crate::FileOperation;
 
 #[allow(clippy::upper_case_acronyms)]
 /// Errors when encoding QR code.
@@ -13,10 +18,62 @@ use image::ImageError;
 pub enum QRCodeError {
     DataTooLong,
     IOError(io::Error),
+    /// An IO error when writing the image file at `path`.
+    FileError {
+        path:      PathBuf,
+        operation: FileOperation,
+        source:    io::Error,
+    },
     #[cfg(feature = "image")]
     ImageError(ImageError),
+    #[cfg(feature = "tiff")]
+    TiffError(TiffError),
+    /// The preview window cannot be opened or updated.
+    #[cfg(feature = "preview")]
+    WindowError(minifb::Error),
     ImageSizeTooSmall,
     ImageSizeTooLarge,
+    /// The image needs more `limit` (`pixels` or `bytes`) than the `OutputLimit` in the options allows.
+    OutputLimitExceeded {
+        limit:     &'static str,
+        requested: u64,
+        max:       u64,
+    },
+    ModuleSizeTooSmall,
+    UnknownFormat,
+    /// A field of the input is invalid.
+    InvalidInput {
+        field:  &'static str,
+        reason: String,
+    },
+    RegionOutOfBounds,
+    PlaceholderNotFound,
+    InvalidPlaceholder,
+    InvalidCsv,
+    InvalidTemplate,
+    UnknownField(String),
+    #[cfg(feature = "ab_glyph")]
+    InvalidFont,
+    MissingFont,
+}
+
+impl QRCodeError {
+    #[inline]
+    pub(crate) fn invalid_input<S: Into<String>>(field: &'static str, reason: S) -> Self {
+        QRCodeError::InvalidInput {
+            field,
+            reason: reason.into(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn file_error(path: &Path, operation: FileOperation, source: io::Error) -> Self {
+        QRCodeError::FileError {
+            path: path.to_path_buf(),
+            operation,
+            source,
+        }
+    }
 }
 
 impl From<io::Error> for QRCodeError {
@@ -34,6 +91,14 @@ impl From<ImageError> for QRCodeError {
     }
 }
 
+#[cfg(feature = "tiff")]
+impl From<TiffError> for QRCodeError {
+    #[inline]
+    fn from(error: TiffError) -> Self {
+        QRCodeError::TiffError(error)
+    }
+}
+
 impl Display for QRCodeError {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
@@ -42,14 +107,79 @@ impl Display for QRCodeError {
                 f.write_str("the supplied data does not fit any QR Code version")
             },
             QRCodeError::IOError(error) => Display::fmt(error, f),
+            QRCodeError::FileError {
+                path,
+                operation,
+                source,
+            } => f.write_fmt(format_args!(
+                "failed to {operation} the file {path:?}: {source}",
+                path = path.as_os_str()
+            )),
             #[cfg(feature = "image")]
             QRCodeError::ImageError(error) => Display::fmt(error, f),
+            #[cfg(feature = "tiff")]
+            QRCodeError::TiffError(error) => Display::fmt(error, f),
+            #[cfg(feature = "preview")]
+            QRCodeError::WindowError(error) => Display::fmt(error, f),
             QRCodeError::ImageSizeTooSmall => {
                 f.write_str("image size is too small to draw the whole QR code")
             },
             QRCodeError::ImageSizeTooLarge => f.write_str("image size is too large to generate"),
+            QRCodeError::OutputLimitExceeded {
+                limit,
+                requested,
+                max,
+            } => f.write_fmt(format_args!(
+                "the image needs {requested} {limit}, which exceeds the limit of {max}"
+            )),
+            QRCodeError::ModuleSizeTooSmall => {
+                f.write_str("module size is too small to be printed reliably")
+            },
+            QRCodeError::UnknownFormat => {
+                f.write_str("the file extension does not match any supported output format")
+            },
+            QRCodeError::InvalidInput {
+                field,
+                reason,
+            } => f.write_fmt(format_args!("invalid {field}: {reason}")),
+            QRCodeError::RegionOutOfBounds => f.write_str("the region is outside the image"),
+            QRCodeError::PlaceholderNotFound => {
+                f.write_str("the placeholder element is not found in the SVG template")
+            },
+            QRCodeError::InvalidPlaceholder => f.write_str(
+                "the placeholder element should have a positive width and height in pixels",
+            ),
+            QRCodeError::InvalidCsv => {
+                f.write_str("the CSV text is malformed or has rows of different lengths")
+            },
+            QRCodeError::InvalidTemplate => f.write_str("the template has an unmatched brace"),
+            QRCodeError::UnknownField(name) => {
+                f.write_fmt(format_args!("the template refers to an unknown field {name:?}"))
+            },
+            #[cfg(feature = "ab_glyph")]
+            QRCodeError::InvalidFont => f.write_str("the font data is invalid"),
+            QRCodeError::MissingFont => {
+                f.write_str("drawing text in raster images needs the `ab_glyph` feature and a font")
+            },
         }
     }
 }
 
-impl Error for QRCodeError {}
+impl Error for QRCodeError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            QRCodeError::IOError(error) => Some(error),
+            QRCodeError::FileError {
+                source, ..
+            } => Some(source),
+            #[cfg(feature = "image")]
+            QRCodeError::ImageError(error) => Some(error),
+            #[cfg(feature = "tiff")]
+            QRCodeError::TiffError(error) => Some(error),
+            #[cfg(feature = "preview")]
+            QRCodeError::WindowError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::hash::{Hash, Hasher};
+
+use crate::{Ecc, QrCodeEcc};
+
+/// The dimensions of a QR code which would be generated, without rendering it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QrDimensions {
+    /// The version from `1` to `40`.
+    pub version: u8,
+    /// The number of modules on each side, i.e. `version * 4 + 17`.
+    pub modules: usize,
+    /// The error correction level, which may be higher than the requested one if it does not increase the version.
+    pub ecc:     QrCodeEcc,
+}
+
+impl QrDimensions {
+    /// The smallest image size which can draw the whole QR code, with one pixel per module and the margins.
+    #[inline]
+    pub const fn min_image_size(&self) -> usize {
+        self.modules + 2
+    }
+}
+
+impl Hash for QrDimensions {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.version.hash(state);
+        self.modules.hash(state);
+        Ecc::from(self.ecc).hash(state);
+    }
+}
@@ -0,0 +1,269 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+};
+
+use crate::{
+    generate_qrcode_advanced, payload_segments, render_to_vec_inner, IntoQrPayload, OutputSpec,
+    QRCodeError, RenderOptions,
+};
+
+#[derive(Debug)]
+struct Entry {
+    image:     Arc<[u8]>,
+    last_used: u64,
+}
+
+#[derive(Debug)]
+enum RenderState {
+    Running,
+    Done(Arc<[u8]>),
+    Failed,
+}
+
+/// A render in progress, which other threads asking for the same payload wait for.
+#[derive(Debug)]
+struct InFlight {
+    state: Mutex<RenderState>,
+    done:  Condvar,
+}
+
+impl InFlight {
+    #[inline]
+    fn new() -> Self {
+        InFlight {
+            state: Mutex::new(RenderState::Running), done: Condvar::new()
+        }
+    }
+
+    /// Wait for the render to finish. `None` is returned if it failed.
+    fn wait(&self) -> Option<Arc<[u8]>> {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+
+        loop {
+            match &*state {
+                RenderState::Running => {
+                    state = self.done.wait(state).unwrap_or_else(|err| err.into_inner());
+                },
+                RenderState::Done(image) => return Some(image.clone()),
+                RenderState::Failed => return None,
+            }
+        }
+    }
+}
+
+/// Finishes a render when dropped, even if rendering panicked, so that waiting threads are woken up.
+struct InFlightGuard<'a> {
+    service:   &'a QrService,
+    payload:   &'a [u8],
+    in_flight: Arc<InFlight>,
+    image:     Option<Arc<[u8]>>,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        {
+            let mut cache = self.service.lock();
+
+            if let Some(image) = self.image.as_ref() {
+                cache.insert(self.payload, image.clone());
+            }
+
+            cache.in_flight.remove(self.payload);
+        }
+
+        *self.in_flight.state.lock().unwrap_or_else(|err| err.into_inner()) =
+            match self.image.take() {
+                Some(image) => RenderState::Done(image),
+                None => RenderState::Failed,
+            };
+
+        self.in_flight.done.notify_all();
+    }
+}
+
+#[derive(Debug)]
+struct LruCache {
+    capacity:  usize,
+    tick:      u64,
+    entries:   HashMap<Arc<[u8]>, Entry>,
+    /// The payloads ordered by their last use, so that the least recently used one is the first.
+    recency:   BTreeMap<u64, Arc<[u8]>>,
+    /// The payloads being rendered, so that concurrent misses on the same payload render it only once.
+    in_flight: HashMap<Arc<[u8]>, Arc<InFlight>>,
+}
+
+impl LruCache {
+    #[inline]
+    fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            tick: 0,
+            entries: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+            in_flight: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, payload: &[u8]) -> Option<Arc<[u8]>> {
+        self.tick += 1;
+
+        let tick = self.tick;
+
+        let entry = self.entries.get_mut(payload)?;
+
+        if let Some(payload) = self.recency.remove(&entry.last_used) {
+            self.recency.insert(tick, payload);
+        }
+
+        entry.last_used = tick;
+
+        Some(entry.image.clone())
+    }
+
+    fn insert(&mut self, payload: &[u8], image: Arc<[u8]>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.tick += 1;
+
+        if let Some(entry) = self.entries.get_mut(payload) {
+            if let Some(payload) = self.recency.remove(&entry.last_used) {
+                self.recency.insert(self.tick, payload);
+            }
+
+            entry.image = image;
+            entry.last_used = self.tick;
+
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            if let Some((_, lru_payload)) = self.recency.pop_first() {
+                self.entries.remove(&lru_payload);
+            }
+        }
+
+        let payload: Arc<[u8]> = payload.into();
+
+        self.recency.insert(self.tick, payload.clone());
+
+        self.entries.insert(payload, Entry {
+            image,
+            last_used: self.tick,
+        });
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+}
+
+/// A thread-safe renderer which memoizes the most recently rendered images by their payloads.
+#[derive(Debug)]
+pub struct QrService {
+    options: RenderOptions,
+    spec:    OutputSpec,
+    cache:   Mutex<LruCache>,
+}
+
+impl QrService {
+    /// Create a service which caches at most `capacity` images.
+    #[inline]
+    pub fn new(options: RenderOptions, spec: OutputSpec, capacity: usize) -> Self {
+        QrService {
+            options,
+            spec,
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    #[inline]
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    #[inline]
+    pub fn spec(&self) -> OutputSpec {
+        self.spec
+    }
+
+    /// Render data to an image, or get the cached image if the same data has been rendered recently. If another thread is rendering the same data, wait for its image instead of rendering it again.
+    pub fn render<D: IntoQrPayload>(&self, data: D) -> Result<Arc<[u8]>, QRCodeError> {
+        let payload = data.into_qr_payload();
+        let payload = payload.as_ref();
+
+        let in_flight = {
+            let mut cache = self.lock();
+
+            if let Some(image) = cache.get(payload) {
+                return Ok(image);
+            }
+
+            match cache.in_flight.get(payload) {
+                Some(in_flight) => Err(in_flight.clone()),
+                None => {
+                    let in_flight = Arc::new(InFlight::new());
+
+                    cache.in_flight.insert(payload.into(), in_flight.clone());
+
+                    Ok(in_flight)
+                },
+            }
+        };
+
+        match in_flight {
+            Ok(in_flight) => {
+                let mut guard = InFlightGuard {
+                    service: self,
+                    payload,
+                    in_flight,
+                    image: None,
+                };
+
+                let image = self.render_inner(payload)?;
+
+                guard.image = Some(image.clone());
+
+                Ok(image)
+            },
+            Err(in_flight) => match in_flight.wait() {
+                Some(image) => Ok(image),
+                // the error is not shared between threads, so render again to get it
+                None => self.render_inner(payload),
+            },
+        }
+    }
+
+    fn render_inner(&self, payload: &[u8]) -> Result<Arc<[u8]>, QRCodeError> {
+        let qr = generate_qrcode_advanced(
+            &payload_segments(payload),
+            self.options.ecc,
+            self.options.version,
+            self.options.mask,
+        )?;
+
+        Ok(render_to_vec_inner(&qr, &self.options, self.spec)?.into())
+    }
+
+    /// The number of cached images.
+    #[inline]
+    pub fn cached_len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Remove all cached images.
+    #[inline]
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, LruCache> {
+        // the cache is always in a consistent state, so a poisoned lock can still be used
+        self.cache.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
@@ -0,0 +1,374 @@
+#[cfg(feature = "image")]
+use std::io::Write;
+
+#[cfg(feature = "image")]
+use image::{
+    codecs::png::{CompressionType, FilterType, PngEncoder},
+    error::{EncodingError, ImageFormatHint},
+    ColorType, ImageEncoder, ImageError, ImageFormat,
+};
+use qrcodegen::QrCode;
+
+use crate::{check_image_size, layout, zone::to_zone_map, QRCodeError, RenderOptions, Zone};
+#[cfg(feature = "image")]
+use crate::{png_chunk, Color, Dithering, IccProfile};
+
+/// Pixels in the margin around the modules.
+pub(crate) const QUIET_ZONE: u8 = 0;
+/// Pixels of light modules.
+pub(crate) const LIGHT: u8 = 1;
+/// Pixels of dark modules in `Zone::Data`. Dark modules in other zones are `DARK + zone as u8`.
+pub(crate) const DARK: u8 = 2;
+/// The number of regions.
+pub(crate) const REGIONS: usize = DARK as usize + 4;
+/// The bytes of buffers of a pixel rendered to RGBA8, a byte of the region map and four bytes of RGBA8 pixels.
+#[cfg(feature = "image")]
+pub(crate) const RGBA_BYTES_PER_PIXEL: u64 = 5;
+/// The bytes of buffers of a pixel of a PNG image, four bytes of RGBA8 pixels and three bytes of their compacted copy.
+#[cfg(feature = "image")]
+pub(crate) const PNG_BYTES_PER_PIXEL: u64 = 7;
+/// The bytes of buffers of a pixel of a 1-bit PNG image, four bytes of RGBA8 pixels, eight bytes of their luminances and at most a byte of packed bits.
+#[cfg(feature = "image")]
+const MONOCHROME_PNG_BYTES_PER_PIXEL: u64 = 13;
+
+/// Classify every pixel of a `size` × `size` image into regions. A pixel of a shaped module is dark if its center is covered by the shape. The output limit is checked against buffers of `bytes_per_pixel` bytes per pixel, the most the caller holds at once.
+pub(crate) fn to_region_map(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    bytes_per_pixel: u64,
+) -> Result<Vec<u8>, QRCodeError> {
+    let shape = options.module_shape;
+    let gap = options.module_gap_fraction();
+
+    check_image_size(size)?;
+
+    options.output_limit.check(size, bytes_per_pixel)?;
+
+    let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+    let s = qr.size();
+
+    let data_size = s as usize * point_size;
+
+    let zones = to_zone_map(qr);
+
+    let mut regions = vec![QUIET_ZONE; size * size];
+
+    for y in margin..(margin + data_size) {
+        let offset = y * size;
+
+        regions[(offset + margin)..(offset + margin + data_size)].fill(LIGHT);
+    }
+
+    for i in 0..s {
+        for j in 0..s {
+            if qr.get_module(j, i) {
+                let x = j as usize * point_size + margin;
+                let y = i as usize * point_size + margin;
+
+                let zone = zones[(i * s + j) as usize];
+
+                let region = DARK + zone as u8;
+
+                if zone == Zone::Data && options.shapes_data_modules() {
+                    let point_size_f = point_size as f64;
+
+                    for dy in 0..point_size {
+                        for dx in 0..point_size {
+                            if shape.covers(point_size_f, gap, dx as f64 + 0.5, dy as f64 + 0.5) {
+                                regions[(y + dy) * size + x + dx] = region;
+                            }
+                        }
+                    }
+
+                    continue;
+                }
+
+                for y in y..(y + point_size) {
+                    let offset = y * size;
+
+                    regions[(offset + x)..(offset + x + point_size)].fill(region);
+                }
+            }
+        }
+    }
+
+    Ok(regions)
+}
+
+/// Render a QR code to RGBA8 pixels painted with the colors in the options. See `to_region_map` for `bytes_per_pixel`.
+#[cfg(feature = "image")]
+pub(crate) fn to_rgba(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    bytes_per_pixel: u64,
+) -> Result<Vec<u8>, QRCodeError> {
+    let regions = to_region_map(qr, size, options, bytes_per_pixel)?;
+
+    let (quiet_zone, light, _) = options.colors();
+
+    let mut palette = [[0u8; 4]; REGIONS];
+
+    palette[QUIET_ZONE as usize] = quiet_zone.to_array();
+    palette[LIGHT as usize] = light.to_array();
+
+    for (color, dark) in palette[DARK as usize..].iter_mut().zip(options.zone_colors()) {
+        *color = dark.to_array();
+    }
+
+    let mut rgba = Vec::with_capacity(regions.len() * 4);
+
+    for region in regions {
+        rgba.extend_from_slice(&palette[region as usize]);
+    }
+
+    let n = options.supersampling as usize;
+    let samples = (n * n) as f64;
+
+    if n > 1 && options.shapes_data_modules() {
+        let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+        let s = qr.size();
+
+        let zones = to_zone_map(qr);
+
+        let data_color = options.zone_colors()[Zone::Data as usize];
+
+        let point_size_f = point_size as f64;
+
+        let gap = options.module_gap_fraction();
+
+        for i in 0..s {
+            for j in 0..s {
+                if !qr.get_module(j, i) || zones[(i * s + j) as usize] != Zone::Data {
+                    continue;
+                }
+
+                let x = j as usize * point_size + margin;
+                let y = i as usize * point_size + margin;
+
+                for dy in 0..point_size {
+                    for dx in 0..point_size {
+                        let mut covered = 0usize;
+
+                        // sample at the centers of n × n subpixels
+                        for sy in 0..n {
+                            for sx in 0..n {
+                                let px = dx as f64 + (sx as f64 + 0.5) / n as f64;
+                                let py = dy as f64 + (sy as f64 + 0.5) / n as f64;
+
+                                if options.module_shape.covers(point_size_f, gap, px, py) {
+                                    covered += 1;
+                                }
+                            }
+                        }
+
+                        let k = ((y + dy) * size + x + dx) * 4;
+
+                        rgba[k..(k + 4)].copy_from_slice(&light.to_array());
+
+                        if covered > 0 {
+                            blend(&mut rgba[k..(k + 4)], data_color, covered as f64 / samples);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(border) = options.border.as_ref() {
+        let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+        let (offset, outer_size) =
+            border.outer_bounds(margin, qr.size() as usize * point_size, point_size);
+
+        for y in 0..size {
+            for x in 0..size {
+                let mut covered = 0usize;
+
+                // sample at the centers of n × n subpixels
+                for sy in 0..n {
+                    for sx in 0..n {
+                        let px = x as f64 + (sx as f64 + 0.5) / n as f64;
+                        let py = y as f64 + (sy as f64 + 0.5) / n as f64;
+
+                        if border.covers(offset, outer_size, px, py) {
+                            covered += 1;
+                        }
+                    }
+                }
+
+                if covered > 0 {
+                    let i = (y * size + x) * 4;
+
+                    blend(&mut rgba[i..(i + 4)], border.color, covered as f64 / samples);
+                }
+            }
+        }
+    }
+
+    Ok(rgba)
+}
+
+/// Paint a color over a RGBA8 pixel with the coverage in the range 0 to 1.
+#[cfg(feature = "image")]
+pub(crate) fn blend(pixel: &mut [u8], color: Color, coverage: f64) {
+    if coverage >= 1.0 {
+        pixel.copy_from_slice(&color.to_array());
+
+        return;
+    }
+
+    let src_a = f64::from(color.a) / 255.0 * coverage;
+    let dst_a = f64::from(pixel[3]) / 255.0;
+
+    let out_a = src_a + dst_a * (1.0 - src_a);
+
+    if out_a == 0.0 {
+        return;
+    }
+
+    for (c, src) in pixel[..3].iter_mut().zip([color.r, color.g, color.b]) {
+        let v = (f64::from(src) * src_a + f64::from(*c) * dst_a * (1.0 - src_a)) / out_a;
+
+        *c = v.round() as u8;
+    }
+
+    pixel[3] = (out_a * 255.0).round() as u8;
+}
+
+/// Convert RGBA8 pixels to the smallest lossless color type among L8 (if `gray` is allowed), RGB8 and RGBA8.
+#[cfg(feature = "image")]
+fn compact(rgba: Vec<u8>, gray: bool) -> (Vec<u8>, ColorType) {
+    let pixels = rgba.chunks_exact(4);
+
+    let opaque = pixels.clone().all(|p| p[3] == 255);
+
+    if opaque {
+        if gray && pixels.clone().all(|p| p[0] == p[1] && p[1] == p[2]) {
+            (pixels.map(|p| p[0]).collect(), ColorType::L8)
+        } else {
+            (pixels.flat_map(|p| [p[0], p[1], p[2]]).collect(), ColorType::Rgb8)
+        }
+    } else {
+        (rgba, ColorType::Rgba8)
+    }
+}
+
+/// Render a QR code to a PNG image with the colors, the ICC profile, the DPI and the bit depth in the options.
+#[cfg(feature = "image")]
+pub(crate) fn to_png_inner<W: Write>(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    mut writer: W,
+) -> Result<(), QRCodeError> {
+    let bytes_per_pixel =
+        if options.monochrome { MONOCHROME_PNG_BYTES_PER_PIXEL } else { PNG_BYTES_PER_PIXEL };
+
+    let rgba = to_rgba(qr, size, options, bytes_per_pixel)?;
+
+    if options.monochrome {
+        return encode_monochrome_png(&rgba, size, size, options.dpi, options.dithering, writer);
+    }
+
+    match options.dpi {
+        Some(dpi) => {
+            let mut png = Vec::with_capacity(4096);
+
+            encode_png(rgba, size, size, options.icc_profile.as_ref(), &mut png)?;
+
+            png_chunk::insert_chunk_after_ihdr(&mut png, b"pHYs", &png_chunk::phys_data(dpi));
+
+            Ok(writer.write_all(&png)?)
+        },
+        None => encode_png(rgba, size, size, options.icc_profile.as_ref(), writer),
+    }
+}
+
+/// Encode RGBA8 pixels to a 1-bit grayscale PNG image. Pixels are composited over white and turned into black and white with `dithering`.
+#[cfg(feature = "image")]
+fn encode_monochrome_png<W: Write>(
+    rgba: &[u8],
+    width: usize,
+    height: usize,
+    dpi: Option<u32>,
+    dithering: Dithering,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    let luminances: Vec<f64> = rgba
+        .chunks_exact(4)
+        .map(|pixel| {
+            let alpha = f64::from(pixel[3]) / 255.0;
+
+            Color::rgb(pixel[0], pixel[1], pixel[2]).luminance() * alpha + (1.0 - alpha)
+        })
+        .collect();
+
+    let bits = dithering.to_bits(&luminances, width, height);
+
+    let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::One);
+    encoder.set_compression(png::Compression::High);
+
+    if let Some(dpi) = dpi {
+        let ppm = png_chunk::dpi_to_ppm(dpi);
+
+        encoder.set_pixel_dims(Some(png::PixelDimensions {
+            xppu: ppm,
+            yppu: ppm,
+            unit: png::Unit::Meter,
+        }));
+    }
+
+    encoder.write_header().and_then(|mut writer| writer.write_image_data(&bits)).map_err(|err| {
+        ImageError::Encoding(EncodingError::new(ImageFormatHint::Exact(ImageFormat::Png), err))
+            .into()
+    })
+}
+
+/// Encode RGBA8 pixels to a PNG image with an optional ICC profile.
+#[cfg(feature = "image")]
+pub(crate) fn encode_png<W: Write>(
+    rgba: Vec<u8>,
+    width: usize,
+    height: usize,
+    icc_profile: Option<&IccProfile>,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    // the color type has to match the color space of the profile
+    let (img_raw, color_type) = match icc_profile {
+        Some(icc_profile) => {
+            if &icc_profile.check_color_space(&[b"RGB ", b"GRAY"], "PNG")? == b"GRAY" {
+                let (img_raw, color_type) = compact(rgba, true);
+
+                if color_type != ColorType::L8 {
+                    return Err(QRCodeError::invalid_input(
+                        "icc_profile",
+                        "a GRAY profile needs opaque gray colors",
+                    ));
+                }
+
+                (img_raw, color_type)
+            } else {
+                compact(rgba, false)
+            }
+        },
+        None => compact(rgba, true),
+    };
+
+    let mut encoder =
+        PngEncoder::new_with_quality(writer, CompressionType::Best, FilterType::NoFilter);
+
+    if let Some(icc_profile) = icc_profile {
+        encoder
+            .set_icc_profile(icc_profile.as_bytes().to_vec())
+            .map_err(ImageError::Unsupported)?;
+    }
+
+    Ok(encoder.write_image(&img_raw, width as u32, height as u32, color_type.into())?)
+}
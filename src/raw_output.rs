@@ -0,0 +1,153 @@
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str,
+    module_layout::{check_image_size, layout},
+    Color, IntoQrPayload, PixelFormat, QRCodeError,
+};
+
+/// Render a QR code to grayscale pixels, black for dark modules and white elsewhere, with a quiet zone of one module.
+#[inline]
+pub(crate) fn to_image_inner(qr: QrCode, size: usize) -> Result<Vec<u8>, QRCodeError> {
+    to_pixels_inner(qr, size, &[0], &[255])
+}
+
+/// Render a QR code to pixels of `dark.len()` bytes, `dark` for dark modules and `light` elsewhere, with a quiet zone of one module.
+fn to_pixels_inner(
+    qr: QrCode,
+    size: usize,
+    dark: &[u8],
+    light: &[u8],
+) -> Result<Vec<u8>, QRCodeError> {
+    check_image_size(size)?;
+
+    let (point_size, margin) = layout(&qr, size, 1)?;
+
+    let s = qr.size();
+
+    let bytes_per_pixel = light.len();
+
+    if (size * size).checked_mul(bytes_per_pixel).is_none() {
+        return Err(QRCodeError::ImageSizeTooLarge);
+    }
+
+    let mut img_raw = light.repeat(size * size);
+
+    for i in 0..s {
+        for j in 0..s {
+            if qr.get_module(i, j) {
+                let x = i as usize * point_size + margin;
+                let y = j as usize * point_size + margin;
+
+                for j in y..(y + point_size) {
+                    let offset = (j * size + x) * bytes_per_pixel;
+
+                    for pixel in img_raw[offset..(offset + point_size * bytes_per_pixel)]
+                        .chunks_exact_mut(bytes_per_pixel)
+                    {
+                        pixel.copy_from_slice(dark);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(img_raw)
+}
+
+#[inline]
+fn to_image_with_pixel_format_inner(
+    qr: QrCode,
+    size: usize,
+    format: PixelFormat,
+    dark_color: Color,
+    light_color: Color,
+) -> Result<Vec<u8>, QRCodeError> {
+    let bytes_per_pixel = format.bytes_per_pixel();
+
+    let dark = format.encode(dark_color);
+    let light = format.encode(light_color);
+
+    to_pixels_inner(qr, size, &dark[..bytes_per_pixel], &light[..bytes_per_pixel])
+}
+
+/// Encode data to raw image in memory.
+pub fn to_image<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_image_inner(generate_qrcode(data, ecc)?, size)
+}
+
+/// Encode text to raw image in memory.
+pub fn to_image_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_image_inner(generate_qrcode_from_str(text, ecc)?, size)
+}
+
+/// Encode segments to raw image in memory.
+pub fn to_image_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_image_inner(generate_qrcode_from_segments(segments, ecc)?, size)
+}
+
+/// Encode data to a raw image in memory with a pixel format, such as RGB565 for SPI TFT displays, so that the buffer can be sent to a display driver as it is. The alpha channels of the colors are ignored.
+pub fn to_image_with_pixel_format<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    format: PixelFormat,
+    dark_color: Color,
+    light_color: Color,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_image_with_pixel_format_inner(
+        generate_qrcode(data, ecc)?,
+        size,
+        format,
+        dark_color,
+        light_color,
+    )
+}
+
+/// Encode text to a raw image in memory with a pixel format, such as RGB565 for SPI TFT displays, so that the buffer can be sent to a display driver as it is. The alpha channels of the colors are ignored.
+pub fn to_image_with_pixel_format_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    format: PixelFormat,
+    dark_color: Color,
+    light_color: Color,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_image_with_pixel_format_inner(
+        generate_qrcode_from_str(text, ecc)?,
+        size,
+        format,
+        dark_color,
+        light_color,
+    )
+}
+
+/// Encode segments to a raw image in memory with a pixel format, such as RGB565 for SPI TFT displays, so that the buffer can be sent to a display driver as it is. The alpha channels of the colors are ignored.
+pub fn to_image_with_pixel_format_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    format: PixelFormat,
+    dark_color: Color,
+    light_color: Color,
+) -> Result<Vec<u8>, QRCodeError> {
+    to_image_with_pixel_format_inner(
+        generate_qrcode_from_segments(segments, ecc)?,
+        size,
+        format,
+        dark_color,
+        light_color,
+    )
+}
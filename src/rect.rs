@@ -0,0 +1,20 @@
+/// A rectangle in pixels, where `(x, y)` is its top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x:      usize,
+    pub y:      usize,
+    pub width:  usize,
+    pub height: usize,
+}
+
+impl Rect {
+    #[inline]
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str, layout,
+    IntoQrPayload, QRCodeError, Rect,
+};
+
+fn render_region_inner(
+    qr: &QrCode,
+    size: usize,
+    rect: Rect,
+    buffer: &mut Vec<u8>,
+) -> Result<(), QRCodeError> {
+    let right = rect.x.checked_add(rect.width).ok_or(QRCodeError::RegionOutOfBounds)?;
+    let bottom = rect.y.checked_add(rect.height).ok_or(QRCodeError::RegionOutOfBounds)?;
+
+    if right > size || bottom > size {
+        return Err(QRCodeError::RegionOutOfBounds);
+    }
+
+    let length = rect.width.checked_mul(rect.height).ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+    let (point_size, margin) = layout(qr, size, 1)?;
+
+    let data_size = qr.size() as usize * point_size;
+
+    // the module index of a pixel coordinate, if it is not in the quiet zone
+    let module = |v: usize| {
+        if v >= margin && v < margin + data_size {
+            Some(((v - margin) / point_size) as i32)
+        } else {
+            None
+        }
+    };
+
+    buffer.clear();
+    buffer.reserve(length);
+
+    for y in rect.y..bottom {
+        match module(y) {
+            Some(j) => {
+                for x in rect.x..right {
+                    let dark = match module(x) {
+                        Some(i) => qr.get_module(i, j),
+                        None => false,
+                    };
+
+                    buffer.push(if dark { 0 } else { 255 });
+                }
+            },
+            None => buffer.resize(buffer.len() + rect.width, 255),
+        }
+    }
+
+    Ok(())
+}
+
+/// Render only a region of the `size` × `size` raw image of the QR code of data (see `to_image`) to `buffer`, which is cleared and then filled with `rect.width` × `rect.height` grayscale pixels. Tiles of a huge image can be rendered one by one without allocating the whole image.
+#[inline]
+pub fn render_region<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    rect: Rect,
+    buffer: &mut Vec<u8>,
+) -> Result<(), QRCodeError> {
+    render_region_inner(&generate_qrcode(data, ecc)?, size, rect, buffer)
+}
+
+/// Render only a region of the `size` × `size` raw image of the QR code of text (see `to_image`) to `buffer`, which is cleared and then filled with `rect.width` × `rect.height` grayscale pixels. Tiles of a huge image can be rendered one by one without allocating the whole image.
+#[inline]
+pub fn render_region_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    rect: Rect,
+    buffer: &mut Vec<u8>,
+) -> Result<(), QRCodeError> {
+    render_region_inner(&generate_qrcode_from_str(text, ecc)?, size, rect, buffer)
+}
+
+/// Render only a region of the `size` × `size` raw image of the QR code of segments (see `to_image`) to `buffer`, which is cleared and then filled with `rect.width` × `rect.height` grayscale pixels. Tiles of a huge image can be rendered one by one without allocating the whole image.
+#[inline]
+pub fn render_region_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    rect: Rect,
+    buffer: &mut Vec<u8>,
+) -> Result<(), QRCodeError> {
+    render_region_inner(&generate_qrcode_from_segments(segments, ecc)?, size, rect, buffer)
+}
@@ -0,0 +1,436 @@
+use crate::{
+    Border, CmykColor, Color, Dithering, IccProfile, Mask, ModuleShape, OutputLimit, OutputProfile,
+    QRCodeError, QrCodeEcc, RenderWarning, SpotColor, SvgFilter, Theme, Validate, Version, Zone,
+};
+
+/// Options shared by every rendered image of a QR code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    pub(crate) ecc:              QrCodeEcc,
+    pub(crate) version:          Option<Version>,
+    pub(crate) mask:             Option<Mask>,
+    pub(crate) description:      Option<String>,
+    pub(crate) icc_profile:      Option<IccProfile>,
+    pub(crate) dark_color:       Color,
+    pub(crate) light_color:      Color,
+    pub(crate) quiet_zone_color: Option<Color>,
+    pub(crate) zone_colors:      [Option<Color>; 4],
+    pub(crate) module_shape:     ModuleShape,
+    pub(crate) module_gap:       u8,
+    pub(crate) border:           Option<Border>,
+    pub(crate) svg_filter:       Option<SvgFilter>,
+    pub(crate) caption:          Option<String>,
+    pub(crate) supersampling:    u8,
+    pub(crate) quiet_zone:       usize,
+    pub(crate) dpi:              Option<u32>,
+    pub(crate) monochrome:       bool,
+    pub(crate) dithering:        Dithering,
+    pub(crate) dark_ink:         CmykColor,
+    pub(crate) light_ink:        CmykColor,
+    pub(crate) quiet_zone_ink:   Option<CmykColor>,
+    pub(crate) dark_spot_color:  Option<SpotColor>,
+    pub(crate) bleed:            usize,
+    pub(crate) crop_marks:       bool,
+    pub(crate) inverted:         bool,
+    pub(crate) output_limit:     OutputLimit,
+}
+
+/// The default options used by the functions without an options parameter.
+pub(crate) static DEFAULT_RENDER_OPTIONS: RenderOptions = RenderOptions::new(QrCodeEcc::Medium);
+
+impl RenderOptions {
+    /// The largest module gap in percent. Wider gaps leave too little dark area for scanners.
+    pub const MAX_MODULE_GAP: u8 = 25;
+
+    /// Create the default options with an error correction level.
+    #[inline]
+    pub const fn new(ecc: QrCodeEcc) -> Self {
+        RenderOptions {
+            ecc,
+            version: None,
+            mask: None,
+            description: None,
+            icc_profile: None,
+            dark_color: Color::BLACK,
+            light_color: Color::WHITE,
+            quiet_zone_color: None,
+            zone_colors: [None; 4],
+            module_shape: ModuleShape::Square,
+            module_gap: 0,
+            border: None,
+            svg_filter: None,
+            caption: None,
+            supersampling: 1,
+            quiet_zone: 1,
+            dpi: None,
+            monochrome: false,
+            dithering: Dithering::Threshold,
+            dark_ink: CmykColor::PURE_BLACK,
+            light_ink: CmykColor::PAPER,
+            quiet_zone_ink: None,
+            dark_spot_color: None,
+            bleed: 0,
+            crop_marks: false,
+            inverted: false,
+            output_limit: OutputLimit::UNLIMITED,
+        }
+    }
+
+    /// Create the preset options of a target.
+    #[inline]
+    pub const fn from_profile(profile: OutputProfile) -> Self {
+        match profile {
+            OutputProfile::Print => {
+                RenderOptions::new(QrCodeEcc::High).quiet_zone(4).dpi(Some(300))
+            },
+            OutputProfile::Web => {
+                RenderOptions::new(QrCodeEcc::Medium).light_color(Color::TRANSPARENT)
+            },
+            OutputProfile::Thermal => RenderOptions::new(QrCodeEcc::Medium).monochrome(true),
+        }
+    }
+
+    /// Set the error correction level.
+    #[inline]
+    pub const fn ecc(mut self, ecc: QrCodeEcc) -> Self {
+        self.ecc = ecc;
+
+        self
+    }
+
+    /// Set a fixed version so that QR codes of different data have the same number of modules. The highest error correction level which fits is used, but not lower than `ecc`. `None` means the smallest version which fits. Use `Version::try_from` to check a number from `1` to `40`.
+    #[inline]
+    pub const fn version(mut self, version: Option<Version>) -> Self {
+        self.version = version;
+
+        self
+    }
+
+    /// Set the mask pattern. `None` means choosing the one with the lowest penalty score, see `mask_penalties`. Use `Mask::try_from` to check a number from `0` to `7`.
+    #[inline]
+    pub const fn mask(mut self, mask: Option<Mask>) -> Self {
+        self.mask = mask;
+
+        self
+    }
+
+    /// Set the description of SVG images. `None` means using the default description and an empty string means no description.
+    #[inline]
+    pub fn description<S: Into<String>>(mut self, description: Option<S>) -> Self {
+        self.description = description.map(|s| s.into());
+
+        self
+    }
+
+    /// Set the color of dark modules for RGB outputs (SVG and PNG). The default value is `Color::BLACK`.
+    #[inline]
+    pub const fn dark_color(mut self, dark_color: Color) -> Self {
+        self.dark_color = dark_color;
+
+        self
+    }
+
+    /// Set the color of light modules for RGB outputs (SVG and PNG). The default value is `Color::WHITE`.
+    #[inline]
+    pub const fn light_color(mut self, light_color: Color) -> Self {
+        self.light_color = light_color;
+
+        self
+    }
+
+    /// Set the color of the quiet zone (the margin around the modules) for RGB outputs (SVG and PNG). `None` means using the color of light modules.
+    #[inline]
+    pub const fn quiet_zone_color(mut self, quiet_zone_color: Option<Color>) -> Self {
+        self.quiet_zone_color = quiet_zone_color;
+
+        self
+    }
+
+    /// Set the color of dark modules in a structural zone, e.g. the finder patterns, for RGB outputs (SVG and PNG). `None` means using the color of dark modules.
+    #[inline]
+    pub const fn zone_color(mut self, zone: Zone, color: Option<Color>) -> Self {
+        self.zone_colors[zone as usize] = color;
+
+        self
+    }
+
+    /// Set the shape of dark modules in the data area of SVG and raster outputs. Curved shapes are anti-aliased in PNG images with `supersampling`.
+    #[inline]
+    pub const fn module_shape(mut self, module_shape: ModuleShape) -> Self {
+        self.module_shape = module_shape;
+
+        self
+    }
+
+    /// Set the gap between dark modules in the data area of SVG and raster outputs, as a percentage of the module size, for the spaced "pixel grid" look. Every module is shrunk around its center. It must not exceed `RenderOptions::MAX_MODULE_GAP`.
+    #[inline]
+    pub const fn module_gap(mut self, percent: u8) -> Self {
+        self.module_gap = percent;
+
+        self
+    }
+
+    /// Set the decorative border drawn around the modules of RGB outputs (SVG and PNG). Parts outside the image are clipped.
+    #[inline]
+    pub const fn border(mut self, border: Option<Border>) -> Self {
+        self.border = border;
+
+        self
+    }
+
+    /// Set the filter effect applied to the dark modules of SVG images.
+    #[inline]
+    pub const fn svg_filter(mut self, svg_filter: Option<SvgFilter>) -> Self {
+        self.svg_filter = svg_filter;
+
+        self
+    }
+
+    /// Set the caption written in a band under SVG images. The band is not covered by the bleed and the crop marks. Other formats cannot draw captions, so rendering them with a caption fails with `QRCodeError::InvalidInput`.
+    #[inline]
+    pub fn caption<S: Into<String>>(mut self, caption: Option<S>) -> Self {
+        self.caption = caption.map(|s| s.into());
+
+        self
+    }
+
+    /// Set the number of samples per pixel along each axis when rendering curved shapes, such as rounded border corners and module shapes, of PNG images. `1` means no anti-aliasing and `0` is treated as `1`. Edges of square modules are always aligned to pixels.
+    #[inline]
+    pub const fn supersampling(mut self, factor: u8) -> Self {
+        self.supersampling = if factor == 0 { 1 } else { factor };
+
+        self
+    }
+
+    /// Set the minimum width of the quiet zone (the margin around the modules) in modules. The default value is `1`, while the QR code specification recommends `4`.
+    #[inline]
+    pub const fn quiet_zone(mut self, quiet_zone: usize) -> Self {
+        self.quiet_zone = quiet_zone;
+
+        self
+    }
+
+    /// Set the resolution embedded in PNG images, in dots per inch.
+    #[inline]
+    pub const fn dpi(mut self, dpi: Option<u32>) -> Self {
+        self.dpi = dpi;
+
+        self
+    }
+
+    /// Set whether to encode PNG images with 1 bit per pixel. Every pixel becomes black or white by its luminance, so colors and transparency are lost. The ICC profile is not embedded.
+    #[inline]
+    pub const fn monochrome(mut self, monochrome: bool) -> Self {
+        self.monochrome = monochrome;
+
+        self
+    }
+
+    /// Set how shades, such as anti-aliased edges, borders and light colors, become black and white in monochrome PNG images. The default value is `Dithering::Threshold`.
+    #[inline]
+    pub const fn dithering(mut self, dithering: Dithering) -> Self {
+        self.dithering = dithering;
+
+        self
+    }
+
+    /// Set the CMYK inks of dark modules for CMYK outputs. The default value is `CmykColor::PURE_BLACK`.
+    #[inline]
+    pub const fn dark_ink(mut self, dark_ink: CmykColor) -> Self {
+        self.dark_ink = dark_ink;
+
+        self
+    }
+
+    /// Set the CMYK inks of light modules for CMYK outputs. The default value is `CmykColor::PAPER`.
+    #[inline]
+    pub const fn light_ink(mut self, light_ink: CmykColor) -> Self {
+        self.light_ink = light_ink;
+
+        self
+    }
+
+    /// Set the CMYK inks of the quiet zone (the margin around the modules) for CMYK outputs. `None` means using the inks of light modules.
+    #[inline]
+    pub const fn quiet_zone_ink(mut self, quiet_zone_ink: Option<CmykColor>) -> Self {
+        self.quiet_zone_ink = quiet_zone_ink;
+
+        self
+    }
+
+    /// Set the spot color of dark modules for PDF outputs. It takes precedence over the dark CMYK inks.
+    #[inline]
+    pub fn dark_spot_color(mut self, dark_spot_color: Option<SpotColor>) -> Self {
+        self.dark_spot_color = dark_spot_color;
+
+        self
+    }
+
+    /// Set the width of the bleed area of vector outputs (SVG and PDF), i.e. how far the background extends beyond the trimmed image. The page grows accordingly.
+    #[inline]
+    pub const fn bleed(mut self, bleed: usize) -> Self {
+        self.bleed = bleed;
+
+        self
+    }
+
+    /// Set whether to draw printer's crop marks and registration marks around the trimmed image of vector outputs (SVG and PDF). The page grows accordingly.
+    #[inline]
+    pub const fn crop_marks(mut self, crop_marks: bool) -> Self {
+        self.crop_marks = crop_marks;
+
+        self
+    }
+
+    /// Set whether to swap the colors (and the inks) of dark and light modules for light-on-dark designs. The quiet zone follows the light modules, so it is painted dark unless its color is set explicitly. Many scanners cannot read inverted QR codes, see `warnings`.
+    #[inline]
+    pub const fn inverted(mut self, inverted: bool) -> Self {
+        self.inverted = inverted;
+
+        self
+    }
+
+    /// Set the limits of the memory needed by raster outputs, so that requests for huge images fail with `QRCodeError::OutputLimitExceeded` before anything is allocated. There are no limits by default.
+    #[inline]
+    pub const fn output_limit(mut self, output_limit: OutputLimit) -> Self {
+        self.output_limit = output_limit;
+
+        self
+    }
+
+    /// Apply a ready-made style by setting the module shape, the border, the caption and the supersampling factor. Colors are kept, so set them before applying a theme to frame a badge with the dark color.
+    pub fn theme(self, theme: Theme) -> Self {
+        let (_, _, dark) = self.colors();
+
+        // the frame of a badge is drawn outside a quiet zone of at least 4 modules, in 2 more modules
+        let badge_padding = self.quiet_zone.max(4);
+
+        let options = self.border(None).caption(None::<String>);
+
+        match theme {
+            Theme::Classic => options.module_shape(ModuleShape::Square).supersampling(1),
+            Theme::Rounded => options.module_shape(ModuleShape::Rounded).supersampling(4),
+            Theme::Dots => options.module_shape(ModuleShape::Dot).supersampling(4),
+            Theme::Badge(caption) => options
+                .module_shape(ModuleShape::Rounded)
+                .supersampling(4)
+                .quiet_zone(badge_padding + 2)
+                .border(Some(Border::new(2, dark).radius(8).padding(badge_padding)))
+                .caption(Some(caption)),
+        }
+    }
+
+    /// Set the ICC color profile embedded in raster images. PNG images need an RGB profile (or a GRAY profile for gray colors), JPEG images need an RGB profile, and TIFF images need a CMYK profile, otherwise rendering fails with `QRCodeError::InvalidInput`.
+    #[inline]
+    pub fn icc_profile(mut self, icc_profile: Option<IccProfile>) -> Self {
+        self.icc_profile = icc_profile;
+
+        self
+    }
+}
+
+impl RenderOptions {
+    /// Check the options for settings which may make rendered QR codes hard to scan.
+    pub fn warnings(&self) -> Vec<RenderWarning> {
+        let mut warnings = Vec::new();
+
+        let (_, light, dark) = self.colors();
+
+        if self.inverted || dark.luminance() > light.luminance() {
+            warnings.push(RenderWarning::InvertedColors);
+        }
+
+        if let Some(border) = self.border.as_ref() {
+            // the QR code specification asks for 4 modules, so a wider quiet zone may hold the border
+            if border.padding < self.quiet_zone.min(4) {
+                warnings.push(RenderWarning::BorderInQuietZone);
+            }
+        }
+
+        warnings
+    }
+
+    /// Whether dark modules in the data area are drawn differently from squares filling their cells.
+    #[inline]
+    pub(crate) fn shapes_data_modules(&self) -> bool {
+        self.module_shape != ModuleShape::Square || self.module_gap > 0
+    }
+
+    /// The module gap as a fraction of the module size.
+    #[inline]
+    pub(crate) fn module_gap_fraction(&self) -> f64 {
+        f64::from(self.module_gap) / 100.0
+    }
+
+    /// The colors painted on the quiet zone, light modules and dark modules.
+    #[inline]
+    pub(crate) fn colors(&self) -> (Color, Color, Color) {
+        let (light, dark) = if self.inverted {
+            (self.dark_color, self.light_color)
+        } else {
+            (self.light_color, self.dark_color)
+        };
+
+        (self.quiet_zone_color.unwrap_or(light), light, dark)
+    }
+
+    /// The colors painted on dark modules of every zone, indexed by `Zone`.
+    #[inline]
+    pub(crate) fn zone_colors(&self) -> [Color; 4] {
+        let (_, _, dark) = self.colors();
+
+        self.zone_colors.map(|color| color.unwrap_or(dark))
+    }
+
+    /// The inks painted on the quiet zone, light modules and dark modules.
+    #[cfg(feature = "tiff")]
+    #[inline]
+    pub(crate) fn inks(&self) -> (CmykColor, CmykColor, CmykColor) {
+        let (light, dark) = if self.inverted {
+            (self.dark_ink, self.light_ink)
+        } else {
+            (self.light_ink, self.dark_ink)
+        };
+
+        (self.quiet_zone_ink.unwrap_or(light), light, dark)
+    }
+}
+
+impl Validate for RenderOptions {
+    fn validate(&self) -> Result<(), QRCodeError> {
+        if self.dpi == Some(0) {
+            return Err(QRCodeError::invalid_input("dpi", "the resolution must be positive"));
+        }
+
+        if self.module_gap > RenderOptions::MAX_MODULE_GAP {
+            return Err(QRCodeError::invalid_input(
+                "module_gap",
+                format!("must not exceed {}% to stay scannable", RenderOptions::MAX_MODULE_GAP),
+            ));
+        }
+
+        if let Some(border) = self.border.as_ref() {
+            if border.width == 0 {
+                return Err(QRCodeError::invalid_input("border", "the width must be positive"));
+            }
+        }
+
+        if let Some(caption) = self.caption.as_deref() {
+            if caption.chars().any(char::is_control) {
+                return Err(QRCodeError::invalid_input(
+                    "caption",
+                    "control characters cannot be rendered",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for RenderOptions {
+    #[inline]
+    fn default() -> Self {
+        RenderOptions::new(QrCodeEcc::Medium)
+    }
+}
@@ -0,0 +1,306 @@
+use qrcodegen::{QrCode, QrSegment};
+
+#[cfg(feature = "jpeg")]
+use crate::jpeg;
+#[cfg(feature = "image")]
+use crate::raster;
+#[cfg(feature = "tiff")]
+use crate::tiff_renderer;
+use crate::{
+    box_shadow_renderer, classify, generate_qrcode_advanced, payload_segments, pdf_renderer,
+    svg_renderer, trace, Artifact, IntoQrPayload, NamedArtifact, OutputFormat, OutputSpec,
+    QRCodeError, RenderOptions, Validate,
+};
+
+#[inline]
+pub(crate) fn render_to_vec_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    spec: OutputSpec,
+) -> Result<Vec<u8>, QRCodeError> {
+    trace::render(spec, || {
+        options.validate()?;
+
+        if options.caption.is_some() && spec.format != OutputFormat::Svg {
+            return Err(QRCodeError::invalid_input("caption", "is only drawn in SVG images"));
+        }
+
+        match spec.format {
+            OutputFormat::Svg => {
+                let mut svg = Vec::with_capacity(svg_renderer::estimate_svg_len(
+                    qr,
+                    spec.size,
+                    options.description.as_deref(),
+                    options,
+                ));
+
+                svg_renderer::to_svg_inner(
+                    qr,
+                    spec.size,
+                    options.description.as_deref(),
+                    options,
+                    &mut svg,
+                )?;
+
+                Ok(svg)
+            },
+            #[cfg(feature = "image")]
+            OutputFormat::Png => {
+                let mut png = Vec::with_capacity(4096);
+
+                raster::to_png_inner(qr, spec.size, options, &mut png)?;
+
+                Ok(png)
+            },
+            #[cfg(feature = "jpeg")]
+            OutputFormat::Jpeg => {
+                let mut jpeg = Vec::with_capacity(8192);
+
+                jpeg::to_jpeg_render_inner(qr, spec.size, options, &mut jpeg)?;
+
+                Ok(jpeg)
+            },
+            OutputFormat::Pdf => {
+                let mut pdf = Vec::with_capacity(8192);
+
+                pdf_renderer::to_pdf_inner(qr, spec.size, options, &mut pdf)?;
+
+                Ok(pdf)
+            },
+            #[cfg(feature = "tiff")]
+            OutputFormat::Tiff => {
+                let mut tiff = Vec::with_capacity(8192);
+
+                tiff_renderer::to_tiff_inner(qr, spec.size, options, &mut tiff)?;
+
+                Ok(tiff)
+            },
+            OutputFormat::BoxShadowHtml => {
+                let mut html = Vec::with_capacity(32768);
+
+                box_shadow_renderer::to_box_shadow_html_inner(qr, spec.size, options, &mut html)?;
+
+                Ok(html)
+            },
+        }
+    })
+}
+
+#[inline]
+fn render_all_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    specs: &[OutputSpec],
+) -> Result<Vec<Artifact>, QRCodeError> {
+    specs
+        .iter()
+        .map(|&spec| {
+            Ok(Artifact {
+                spec,
+                data: render_to_vec_inner(qr, options, spec)?,
+            })
+        })
+        .collect()
+}
+
+fn render_asset_set_inner(
+    qr: &QrCode,
+    options: &RenderOptions,
+    name: &str,
+    spec: OutputSpec,
+    densities: &[usize],
+) -> Result<Vec<NamedArtifact>, QRCodeError> {
+    densities
+        .iter()
+        .map(|&density| {
+            let size = spec.size.checked_mul(density).ok_or(QRCodeError::ImageSizeTooLarge)?;
+
+            let spec = OutputSpec::new(spec.format, size);
+
+            let extension = spec.format.extension();
+
+            let name = if density == 1 {
+                format!("{name}.{extension}")
+            } else {
+                format!("{name}@{density}x.{extension}")
+            };
+
+            Ok(NamedArtifact {
+                name,
+                density,
+                artifact: Artifact {
+                    spec,
+                    data: render_to_vec_inner(qr, options, spec)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Encode data to an image in memory with options.
+#[inline]
+pub fn render<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+    spec: OutputSpec,
+) -> Result<Vec<u8>, QRCodeError> {
+    render_to_vec_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        spec,
+    )
+}
+
+/// Encode text to an image in memory with options.
+#[inline]
+pub fn render_from_str<S: AsRef<str>>(
+    text: S,
+    options: &RenderOptions,
+    spec: OutputSpec,
+) -> Result<Vec<u8>, QRCodeError> {
+    render_to_vec_inner(
+        &generate_qrcode_advanced(
+            &classify::make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        spec,
+    )
+}
+
+/// Encode segments to an image in memory with options.
+#[inline]
+pub fn render_from_segments(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    spec: OutputSpec,
+) -> Result<Vec<u8>, QRCodeError> {
+    render_to_vec_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        spec,
+    )
+}
+
+/// Encode data once and render it to images of several formats and sizes in memory with options, in the order of `specs`.
+#[inline]
+pub fn render_all<D: IntoQrPayload>(
+    data: D,
+    options: &RenderOptions,
+    specs: &[OutputSpec],
+) -> Result<Vec<Artifact>, QRCodeError> {
+    render_all_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        specs,
+    )
+}
+
+/// Encode text once and render it to images of several formats and sizes in memory with options, in the order of `specs`.
+#[inline]
+pub fn render_all_from_str<S: AsRef<str>>(
+    text: S,
+    options: &RenderOptions,
+    specs: &[OutputSpec],
+) -> Result<Vec<Artifact>, QRCodeError> {
+    render_all_inner(
+        &generate_qrcode_advanced(
+            &classify::make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        specs,
+    )
+}
+
+/// Encode segments once and render them to images of several formats and sizes in memory with options, in the order of `specs`.
+#[inline]
+pub fn render_all_from_segments(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    specs: &[OutputSpec],
+) -> Result<Vec<Artifact>, QRCodeError> {
+    render_all_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        specs,
+    )
+}
+
+/// Encode data once and render it at several pixel densities, e.g. `&[1, 2, 3]`, with options. The size in `spec` is for the density `1`. The artifacts are named `{name}.{extension}` for the density `1` and `{name}@{density}x.{extension}` for the others.
+#[inline]
+pub fn render_asset_set<D: IntoQrPayload, S: AsRef<str>>(
+    data: D,
+    options: &RenderOptions,
+    name: S,
+    spec: OutputSpec,
+    densities: &[usize],
+) -> Result<Vec<NamedArtifact>, QRCodeError> {
+    render_asset_set_inner(
+        &generate_qrcode_advanced(
+            &payload_segments(data),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        name.as_ref(),
+        spec,
+        densities,
+    )
+}
+
+/// Encode text once and render it at several pixel densities, e.g. `&[1, 2, 3]`, with options. The size in `spec` is for the density `1`. The artifacts are named `{name}.{extension}` for the density `1` and `{name}@{density}x.{extension}` for the others.
+#[inline]
+pub fn render_asset_set_from_str<T: AsRef<str>, S: AsRef<str>>(
+    text: T,
+    options: &RenderOptions,
+    name: S,
+    spec: OutputSpec,
+    densities: &[usize],
+) -> Result<Vec<NamedArtifact>, QRCodeError> {
+    render_asset_set_inner(
+        &generate_qrcode_advanced(
+            &classify::make_segments(text.as_ref().as_bytes()),
+            options.ecc,
+            options.version,
+            options.mask,
+        )?,
+        options,
+        name.as_ref(),
+        spec,
+        densities,
+    )
+}
+
+/// Encode segments once and render them at several pixel densities, e.g. `&[1, 2, 3]`, with options. The size in `spec` is for the density `1`. The artifacts are named `{name}.{extension}` for the density `1` and `{name}@{density}x.{extension}` for the others.
+#[inline]
+pub fn render_asset_set_from_segments<S: AsRef<str>>(
+    segments: &[QrSegment],
+    options: &RenderOptions,
+    name: S,
+    spec: OutputSpec,
+    densities: &[usize],
+) -> Result<Vec<NamedArtifact>, QRCodeError> {
+    render_asset_set_inner(
+        &generate_qrcode_advanced(segments, options.ecc, options.version, options.mask)?,
+        options,
+        name.as_ref(),
+        spec,
+        densities,
+    )
+}
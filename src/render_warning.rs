@@ -0,0 +1,38 @@
+use std::fmt::{self, Display, Formatter};
+
+/// Potential problems of rendered images which do not stop them from being rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderWarning {
+    /// Dark modules are painted lighter than light modules. Many scanners cannot read such QR codes.
+    InvertedColors,
+    /// The QR code needs a version above `RenderWarning::MAX_COMFORTABLE_VERSION`. Its modules are small, so it is hard to scan from screens and small prints.
+    DenseCode { version: u8 },
+    /// The padding of the border is narrower than the quiet zone (or 4 modules, which the QR code specification asks for, if the quiet zone is wider), so the border is drawn on the quiet zone, which may stop scanners from finding the QR code.
+    BorderInQuietZone,
+}
+
+impl RenderWarning {
+    /// The highest version which is comfortable to scan with phone cameras.
+    pub const MAX_COMFORTABLE_VERSION: u8 = 10;
+}
+
+impl Display for RenderWarning {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            RenderWarning::InvertedColors => f.write_str(
+                "dark modules are painted lighter than light modules, which many scanners cannot \
+                 read",
+            ),
+            RenderWarning::DenseCode {
+                version,
+            } => f.write_fmt(format_args!(
+                "version {version} is dense, so the QR code is hard to scan from screens and \
+                 small prints"
+            )),
+            RenderWarning::BorderInQuietZone => f.write_str(
+                "the border is drawn on the quiet zone, which scanners need to be clear",
+            ),
+        }
+    }
+}
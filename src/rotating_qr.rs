@@ -0,0 +1,183 @@
+use std::{
+    fmt::{self, Debug, Formatter},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    classify::make_segments, generate_qrcode_advanced, render_to_vec_inner, OutputSpec,
+    QRCodeError, RenderOptions,
+};
+
+const BLOCK_SIZE: usize = 64;
+
+/// The longest interval, 366 days, in seconds.
+const MAX_INTERVAL: u64 = 366 * 24 * 60 * 60;
+
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; BLOCK_SIZE];
+
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5C));
+    outer.update(inner.finalize());
+
+    outer.finalize().into()
+}
+
+/// A generator of time-bound payloads for check-in and attendance codes. Every interval has its own payload, `<timestamp>.<HMAC>`, where the timestamp is the start of the interval in Unix seconds and the HMAC-SHA256 of the timestamp is in uppercase hexadecimal, so that it is encoded in the compact alphanumeric mode and a screenshot stops working once the interval is over.
+#[derive(Clone)]
+pub struct RotatingQr {
+    secret:   Vec<u8>,
+    interval: u64,
+}
+
+impl Debug for RotatingQr {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingQr").field("interval", &self.interval).finish_non_exhaustive()
+    }
+}
+
+impl RotatingQr {
+    /// Create a generator with a secret shared with the verifier and an interval of whole seconds, at most 366 days.
+    pub fn new<S: Into<Vec<u8>>>(secret: S, interval: Duration) -> Result<Self, QRCodeError> {
+        let secret = secret.into();
+
+        if secret.is_empty() {
+            return Err(QRCodeError::invalid_input("secret", "must not be empty"));
+        }
+
+        let interval = interval.as_secs();
+
+        if interval == 0 {
+            return Err(QRCodeError::invalid_input("interval", "must be at least one second"));
+        }
+
+        if interval > MAX_INTERVAL {
+            return Err(QRCodeError::invalid_input("interval", "must be at most 366 days"));
+        }
+
+        Ok(RotatingQr {
+            secret,
+            interval,
+        })
+    }
+
+    /// The start of the interval containing `time`, in Unix seconds.
+    #[inline]
+    pub fn timestamp_at(&self, time: SystemTime) -> u64 {
+        let seconds = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+
+        seconds - seconds % self.interval
+    }
+
+    /// The time left until the payload at `time` expires. It is `Duration::MAX` if the end of the interval is out of the range of `SystemTime`.
+    #[inline]
+    pub fn expires_in(&self, time: SystemTime) -> Duration {
+        self.timestamp_at(time)
+            .checked_add(self.interval)
+            .and_then(|end| UNIX_EPOCH.checked_add(Duration::from_secs(end)))
+            .map_or(Duration::MAX, |end| end.duration_since(time).unwrap_or_default())
+    }
+
+    /// The payload of the interval containing `time`.
+    #[inline]
+    pub fn payload_at(&self, time: SystemTime) -> String {
+        self.payload_of(self.timestamp_at(time))
+    }
+
+    fn payload_of(&self, timestamp: u64) -> String {
+        let timestamp = timestamp.to_string();
+
+        let mut payload = String::with_capacity(timestamp.len() + 65);
+
+        payload.push_str(&timestamp);
+        payload.push('.');
+
+        for b in hmac_sha256(&self.secret, timestamp.as_bytes()) {
+            payload.push_str(&format!("{b:02X}"));
+        }
+
+        payload
+    }
+
+    /// The payload of the current interval.
+    #[inline]
+    pub fn payload(&self) -> String {
+        self.payload_at(SystemTime::now())
+    }
+
+    /// Check whether a scanned payload was generated with the same secret within `skew` intervals of `time`.
+    pub fn verify_at<S: AsRef<str>>(&self, payload: S, time: SystemTime, skew: u64) -> bool {
+        let payload = payload.as_ref();
+
+        let timestamp = match payload.split_once('.').and_then(|(t, _)| t.parse::<u64>().ok()) {
+            Some(timestamp) => timestamp,
+            None => return false,
+        };
+
+        if timestamp % self.interval != 0 {
+            return false;
+        }
+
+        let current = self.timestamp_at(time);
+
+        if current.abs_diff(timestamp) / self.interval > skew {
+            return false;
+        }
+
+        let expected = self.payload_of(timestamp);
+
+        // compare in constant time
+        expected.len() == payload.len()
+            && expected.bytes().zip(payload.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+
+    /// Check whether a scanned payload was generated with the same secret within `skew` intervals of now.
+    #[inline]
+    pub fn verify<S: AsRef<str>>(&self, payload: S, skew: u64) -> bool {
+        self.verify_at(payload, SystemTime::now(), skew)
+    }
+
+    /// Render the payload of the interval containing `time` with options.
+    pub fn render_at(
+        &self,
+        time: SystemTime,
+        options: &RenderOptions,
+        spec: OutputSpec,
+    ) -> Result<Vec<u8>, QRCodeError> {
+        let payload = self.payload_at(time);
+
+        render_to_vec_inner(
+            &generate_qrcode_advanced(
+                &make_segments(payload.as_bytes()),
+                options.ecc,
+                options.version,
+                options.mask,
+            )?,
+            options,
+            spec,
+        )
+    }
+
+    /// Render the payload of the current interval with options. Call it again after `expires_in` to get the next code.
+    #[inline]
+    pub fn render(
+        &self,
+        options: &RenderOptions,
+        spec: OutputSpec,
+    ) -> Result<Vec<u8>, QRCodeError> {
+        self.render_at(SystemTime::now(), options, spec)
+    }
+}
@@ -0,0 +1,152 @@
+use std::iter::FusedIterator;
+
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    check_image_size, generate_qrcode, generate_qrcode_from_segments, generate_qrcode_from_str,
+    layout, IntoQrPayload, QRCodeError,
+};
+
+/// The rows of a raw grayscale image of a QR code, the same as `to_image` makes. Only one row per module row is rendered, so the memory is about `size * (modules + 1)` bytes instead of `size * size`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scanlines {
+    size:       usize,
+    point_size: usize,
+    margin:     usize,
+    modules:    usize,
+    /// A blank row followed by a row for each module row.
+    rows:       Vec<u8>,
+}
+
+impl Scanlines {
+    fn new(qr: &QrCode, size: usize) -> Result<Self, QRCodeError> {
+        check_image_size(size)?;
+
+        let (point_size, margin) = layout(qr, size, 1)?;
+
+        let s = qr.size();
+        let modules = s as usize;
+
+        let mut rows = vec![255u8; size * (modules + 1)];
+
+        for y in 0..s {
+            let row = &mut rows[(y as usize + 1) * size..(y as usize + 2) * size];
+
+            for x in 0..s {
+                if qr.get_module(x, y) {
+                    let start = x as usize * point_size + margin;
+
+                    row[start..(start + point_size)].fill(0);
+                }
+            }
+        }
+
+        Ok(Scanlines {
+            size,
+            point_size,
+            margin,
+            modules,
+            rows,
+        })
+    }
+
+    /// The width and height of the image.
+    #[inline]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Get the row at `y`, one byte per pixel.
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<&[u8]> {
+        if y >= self.size {
+            return None;
+        }
+
+        let index = match y.checked_sub(self.margin) {
+            Some(offset) if offset / self.point_size < self.modules => offset / self.point_size + 1,
+            _ => 0,
+        };
+
+        Some(&self.rows[index * self.size..(index + 1) * self.size])
+    }
+
+    /// Iterate over the rows from the top.
+    #[inline]
+    pub fn iter(&self) -> ScanlineIter<'_> {
+        ScanlineIter {
+            scanlines: self, y: 0
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Scanlines {
+    type IntoIter = ScanlineIter<'a>;
+    type Item = &'a [u8];
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the rows of `Scanlines`.
+#[derive(Debug, Clone)]
+pub struct ScanlineIter<'a> {
+    scanlines: &'a Scanlines,
+    y:         usize,
+}
+
+impl<'a> Iterator for ScanlineIter<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.scanlines.row(self.y)?;
+
+        self.y += 1;
+
+        Some(row)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.scanlines.size - self.y;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for ScanlineIter<'_> {}
+
+impl FusedIterator for ScanlineIter<'_> {}
+
+/// Encode data to the scanlines of a raw image, rendering rows on demand instead of a full frame.
+#[inline]
+pub fn to_scanlines<D: IntoQrPayload>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Scanlines, QRCodeError> {
+    Scanlines::new(&generate_qrcode(data, ecc)?, size)
+}
+
+/// Encode text to the scanlines of a raw image, rendering rows on demand instead of a full frame.
+#[inline]
+pub fn to_scanlines_from_str<S: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Scanlines, QRCodeError> {
+    Scanlines::new(&generate_qrcode_from_str(text, ecc)?, size)
+}
+
+/// Encode segments to the scanlines of a raw image, rendering rows on demand instead of a full frame.
+#[inline]
+pub fn to_scanlines_from_segments(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+) -> Result<Scanlines, QRCodeError> {
+    Scanlines::new(&generate_qrcode_from_segments(segments, ecc)?, size)
+}
@@ -0,0 +1,55 @@
+use crate::{IntoQrPayload, QRCodeError};
+
+#[inline]
+fn count_digits(n: usize) -> usize {
+    n.to_string().len()
+}
+
+/// Split data into payloads of at most `max_bytes_per_code` bytes, each prefixed with `<index>/<total>:` (starting from `1`), for ad-hoc transfers of large data across several QR codes. Every payload can be passed to any function which encodes data.
+///
+/// Unlike Structured Append, scanners do not join the codes by themselves. The receiver strips the prefixes and concatenates the chunks in order. A chunk may end in the middle of a UTF-8 character.
+pub fn split_into_codes<D: IntoQrPayload>(
+    data: D,
+    max_bytes_per_code: usize,
+) -> Result<Vec<Vec<u8>>, QRCodeError> {
+    let data = data.into_qr_payload();
+    let data = data.as_ref();
+
+    // the prefix grows with the number of codes, so widen it until the number fits
+    let mut digits = 1;
+
+    let (chunk_len, total) = loop {
+        let prefix_len = digits * 2 + 2;
+
+        if max_bytes_per_code <= prefix_len {
+            return Err(QRCodeError::invalid_input(
+                "max_bytes_per_code",
+                format!("must be larger than the {prefix_len}-byte index prefix"),
+            ));
+        }
+
+        let chunk_len = max_bytes_per_code - prefix_len;
+        let total = ((data.len() + chunk_len - 1) / chunk_len).max(1);
+
+        if count_digits(total) <= digits {
+            break (chunk_len, total);
+        }
+
+        digits += 1;
+    };
+
+    let mut codes = Vec::with_capacity(total);
+
+    for index in 0..total {
+        let chunk =
+            &data[(index * chunk_len).min(data.len())..((index + 1) * chunk_len).min(data.len())];
+
+        let mut code = format!("{}/{total}:", index + 1).into_bytes();
+
+        code.extend_from_slice(chunk);
+
+        codes.push(code);
+    }
+
+    Ok(codes)
+}
@@ -0,0 +1,34 @@
+use crate::CmykColor;
+
+/// A named spot color (e.g. `"PANTONE 186 C"`) which is printed with its own ink plate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SpotColor {
+    pub(crate) name:      String,
+    pub(crate) alternate: CmykColor,
+    pub(crate) tint:      u8,
+}
+
+impl SpotColor {
+    /// Create a spot color with a full tint. The alternate CMYK color is used by viewers and devices which do not have the spot ink.
+    #[inline]
+    pub fn new<S: Into<String>>(name: S, alternate: CmykColor) -> Self {
+        SpotColor {
+            name: name.into(),
+            alternate,
+            tint: 100,
+        }
+    }
+
+    /// Set the tint as a percentage from `0` to `100`. Percentages larger than `100` are treated as `100`.
+    #[inline]
+    pub fn tint(mut self, tint: u8) -> Self {
+        self.tint = tint.min(100);
+
+        self
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
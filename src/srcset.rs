@@ -0,0 +1,80 @@
+use std::fmt::Write;
+
+use crate::{base64, escape, NamedArtifact, OutputFormat};
+
+/// Where the images of an `<img>` snippet are loaded from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImageSource {
+    /// Embed every image as a `data:` URI.
+    DataUri,
+    /// Load every image from the URL of the prefix followed by the artifact name, e.g. `https://example.com/assets/`.
+    UrlPrefix(String),
+}
+
+impl OutputFormat {
+    /// Get the MIME type of the format.
+    #[inline]
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            OutputFormat::Svg => "image/svg+xml",
+            #[cfg(feature = "image")]
+            OutputFormat::Png => "image/png",
+            #[cfg(feature = "jpeg")]
+            OutputFormat::Jpeg => "image/jpeg",
+            OutputFormat::Pdf => "application/pdf",
+            #[cfg(feature = "tiff")]
+            OutputFormat::Tiff => "image/tiff",
+            OutputFormat::BoxShadowHtml => "text/html",
+        }
+    }
+}
+
+impl NamedArtifact {
+    /// Encode the image to a `data:` URI with Base64.
+    pub fn to_data_uri(&self) -> String {
+        let mut s = format!("data:{};base64,", self.artifact.spec.format.mime_type());
+
+        base64::encode_to_string(&self.artifact.data, &mut s);
+
+        s
+    }
+}
+
+/// Create an `<img>` element whose `srcset` lists the artifacts of `render_asset_set` with their pixel densities. The `src`, `width` and `height` come from the artifact with the lowest density.
+pub fn srcset_html<S: AsRef<str>>(
+    artifacts: &[NamedArtifact],
+    alt: S,
+    source: &ImageSource,
+) -> String {
+    let url = |artifact: &NamedArtifact| match source {
+        ImageSource::DataUri => artifact.to_data_uri(),
+        ImageSource::UrlPrefix(prefix) => format!("{prefix}{}", artifact.name),
+    };
+
+    let mut html = String::from("<img");
+
+    if let Some(base) = artifacts.iter().min_by_key(|artifact| artifact.density) {
+        let size = base.artifact.spec.size / base.density.max(1);
+
+        html.push_str(" src=\"");
+        escape::encode_double_quoted_attribute_to_string(url(base), &mut html);
+        html.push_str("\" srcset=\"");
+
+        for (i, artifact) in artifacts.iter().enumerate() {
+            if i > 0 {
+                html.push_str(", ");
+            }
+
+            escape::encode_double_quoted_attribute_to_string(url(artifact), &mut html);
+            let _ = write!(html, " {}x", artifact.density);
+        }
+
+        let _ = write!(html, "\" width=\"{size}\" height=\"{size}\"");
+    }
+
+    html.push_str(" alt=\"");
+    escape::encode_double_quoted_attribute_to_string(alt.as_ref(), &mut html);
+    html.push_str("\">");
+
+    html
+}
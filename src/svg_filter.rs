@@ -0,0 +1,53 @@
+use std::io::{self, Write};
+
+use crate::Color;
+
+/// A filter effect applied to the dark modules of SVG images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SvgFilter {
+    /// A blurred shadow moved by `dx` and `dy` pixels. `blur` is the standard deviation of the blur in pixels.
+    DropShadow { dx: i32, dy: i32, blur: u32, color: Color },
+    /// A blurred halo around the modules. `blur` is the standard deviation of the blur in pixels.
+    Glow { blur: u32, color: Color },
+}
+
+/// The ID of the `<filter>` element.
+pub(crate) const SVG_FILTER_ID: &str = "qrcode-filter";
+
+impl SvgFilter {
+    /// Write the `<defs>` element containing the filter which covers the whole page.
+    pub(crate) fn write_defs<W: Write>(
+        &self,
+        mut writer: W,
+        page_size: usize,
+    ) -> Result<(), io::Error> {
+        let (dx, dy, blur, color) = match *self {
+            SvgFilter::DropShadow {
+                dx,
+                dy,
+                blur,
+                color,
+            } => (dx, dy, blur, color),
+            SvgFilter::Glow {
+                blur,
+                color,
+            } => (0, 0, blur, color),
+        };
+
+        writer.write_fmt(format_args!(
+            "\t<defs>\n\t\t<filter id=\"{SVG_FILTER_ID}\" filterUnits=\"userSpaceOnUse\" x=\"0\" \
+             y=\"0\" width=\"{page_size}\" height=\"{page_size}\">\n\t\t\t<feGaussianBlur \
+             in=\"SourceAlpha\" stdDeviation=\"{blur}\"/>\n\t\t\t<feOffset dx=\"{dx}\" \
+             dy=\"{dy}\" result=\"shadow\"/>\n\t\t\t<feFlood flood-color=\"{}\"",
+            color.to_hex()
+        ))?;
+
+        if color.a < 255 {
+            writer.write_fmt(format_args!(" flood-opacity=\"{}\"", color.opacity()))?;
+        }
+
+        writer.write_all(
+            b"/>\n\t\t\t<feComposite in2=\"shadow\" operator=\"in\"/>\n\t\t\t<feMerge>\n\t\t\t\t<feMergeNode/>\n\t\t\t\t<feMergeNode in=\"SourceGraphic\"/>\n\t\t\t</feMerge>\n\t\t</filter>\n\t</defs>\n",
+        )
+    }
+}
@@ -0,0 +1,326 @@
+use std::{
+    fmt,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use qrcodegen::{QrCode, QrCodeEcc, QrSegment};
+
+use crate::{
+    fmt_writer::FmtWriter, generate_qrcode, generate_qrcode_from_segments,
+    generate_qrcode_from_str, render_options::DEFAULT_RENDER_OPTIONS, svg_renderer, svg_template,
+    trace, FileOptions, IntoQrPayload, OutputFormat, OutputSpec, QRCodeError,
+};
+
+#[inline]
+fn to_svg_inner<S: AsRef<str>, W: Write>(
+    qr: QrCode,
+    size: usize,
+    description: Option<S>,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    trace::render_to_writer(OutputSpec::new(OutputFormat::Svg, size), writer, |writer| {
+        svg_renderer::to_svg_inner(
+            &qr,
+            size,
+            description.as_ref().map(|s| s.as_ref()),
+            &DEFAULT_RENDER_OPTIONS,
+            writer,
+        )
+    })
+}
+
+#[inline]
+fn to_svg_to_string_inner<S: AsRef<str>>(
+    qr: QrCode,
+    size: usize,
+    description: Option<S>,
+) -> Result<String, QRCodeError> {
+    let mut svg = String::with_capacity(svg_renderer::estimate_svg_len(
+        &qr,
+        size,
+        description.as_ref().map(|s| s.as_ref()),
+        &DEFAULT_RENDER_OPTIONS,
+    ));
+
+    to_svg_inner(qr, size, description, FmtWriter::new(&mut svg))?;
+
+    Ok(svg)
+}
+
+/// Buffer the many small writes of the path data, so that unbuffered writers such as sockets do not get a write per module.
+#[inline]
+fn to_svg_to_writer_inner<S: AsRef<str>, W: Write>(
+    qr: QrCode,
+    size: usize,
+    description: Option<S>,
+    writer: W,
+) -> Result<(), QRCodeError> {
+    let capacity = svg_renderer::estimate_svg_len(
+        &qr,
+        size,
+        description.as_ref().map(|s| s.as_ref()),
+        &DEFAULT_RENDER_OPTIONS,
+    );
+
+    to_svg_inner(qr, size, description, BufWriter::with_capacity(capacity, writer))
+}
+
+#[inline]
+fn to_svg_to_file_inner<S: AsRef<str>, P: AsRef<Path>>(
+    qr: QrCode,
+    size: usize,
+    description: Option<S>,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    options.write(path, |file| to_svg_inner(qr, size, description, file))
+}
+
+/// Encode data to a SVG image in memory.
+#[inline]
+pub fn to_svg_to_string<D: IntoQrPayload, DESC: AsRef<str>>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+) -> Result<String, QRCodeError> {
+    to_svg_to_string_inner(generate_qrcode(data, ecc)?, size, description)
+}
+
+/// Encode text to a SVG image in memory.
+#[inline]
+pub fn to_svg_to_string_from_str<S: AsRef<str>, DESC: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+) -> Result<String, QRCodeError> {
+    to_svg_to_string_inner(generate_qrcode_from_str(text, ecc)?, size, description)
+}
+
+/// Encode segments to a SVG image in memory.
+#[inline]
+pub fn to_svg_to_string_from_segments<DESC: AsRef<str>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+) -> Result<String, QRCodeError> {
+    to_svg_to_string_inner(generate_qrcode_from_segments(segments, ecc)?, size, description)
+}
+
+/// Encode data to a SVG image via a file path.
+#[inline]
+pub fn to_svg_to_file<D: IntoQrPayload, DESC: AsRef<str>, P: AsRef<Path>>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_svg_to_file_inner(generate_qrcode(data, ecc)?, size, description, path, FileOptions::new())
+}
+
+/// Encode text to a SVG image via a file path.
+#[inline]
+pub fn to_svg_to_file_from_str<S: AsRef<str>, DESC: AsRef<str>, P: AsRef<Path>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_svg_to_file_inner(
+        generate_qrcode_from_str(text, ecc)?,
+        size,
+        description,
+        path,
+        FileOptions::new(),
+    )
+}
+
+/// Encode segments to a SVG image via a file path.
+#[inline]
+pub fn to_svg_to_file_from_segments<DESC: AsRef<str>, P: AsRef<Path>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    path: P,
+) -> Result<(), QRCodeError> {
+    to_svg_to_file_inner(
+        generate_qrcode_from_segments(segments, ecc)?,
+        size,
+        description,
+        path,
+        FileOptions::new(),
+    )
+}
+
+/// Encode data to a SVG image via a file path with options.
+#[inline]
+pub fn to_svg_to_file_with_options<D: IntoQrPayload, DESC: AsRef<str>, P: AsRef<Path>>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    to_svg_to_file_inner(generate_qrcode(data, ecc)?, size, description, path, options)
+}
+
+/// Encode text to a SVG image via a file path with options.
+#[inline]
+pub fn to_svg_to_file_from_str_with_options<S: AsRef<str>, DESC: AsRef<str>, P: AsRef<Path>>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    to_svg_to_file_inner(generate_qrcode_from_str(text, ecc)?, size, description, path, options)
+}
+
+/// Encode segments to a SVG image via a file path with options.
+#[inline]
+pub fn to_svg_to_file_from_segments_with_options<DESC: AsRef<str>, P: AsRef<Path>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    path: P,
+    options: FileOptions,
+) -> Result<(), QRCodeError> {
+    to_svg_to_file_inner(
+        generate_qrcode_from_segments(segments, ecc)?,
+        size,
+        description,
+        path,
+        options,
+    )
+}
+
+/// Encode data to a SVG image via a writer.
+#[inline]
+pub fn to_svg_to_writer<D: IntoQrPayload, DESC: AsRef<str>, W: Write>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_svg_to_writer_inner(generate_qrcode(data, ecc)?, size, description, writer)
+}
+
+/// Encode text to a SVG image via a writer.
+#[inline]
+pub fn to_svg_to_writer_from_str<S: AsRef<str>, DESC: AsRef<str>, W: Write>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_svg_to_writer_inner(generate_qrcode_from_str(text, ecc)?, size, description, writer)
+}
+
+/// Encode segments to a SVG image via a writer.
+#[inline]
+pub fn to_svg_to_writer_from_segments<DESC: AsRef<str>, W: Write>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_svg_to_writer_inner(generate_qrcode_from_segments(segments, ecc)?, size, description, writer)
+}
+
+/// Encode data to a SVG image via a `fmt::Write`, such as a `String` or a `fmt::Formatter`.
+#[inline]
+pub fn to_svg_to_fmt_writer<D: IntoQrPayload, DESC: AsRef<str>, W: fmt::Write>(
+    data: D,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_svg_inner(generate_qrcode(data, ecc)?, size, description, FmtWriter::new(writer))
+}
+
+/// Encode text to a SVG image via a `fmt::Write`, such as a `String` or a `fmt::Formatter`.
+#[inline]
+pub fn to_svg_to_fmt_writer_from_str<S: AsRef<str>, DESC: AsRef<str>, W: fmt::Write>(
+    text: S,
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_svg_inner(generate_qrcode_from_str(text, ecc)?, size, description, FmtWriter::new(writer))
+}
+
+/// Encode segments to a SVG image via a `fmt::Write`, such as a `String` or a `fmt::Formatter`.
+#[inline]
+pub fn to_svg_to_fmt_writer_from_segments<DESC: AsRef<str>, W: fmt::Write>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    size: usize,
+    description: Option<DESC>,
+    writer: &mut W,
+) -> Result<(), QRCodeError> {
+    to_svg_inner(
+        generate_qrcode_from_segments(segments, ecc)?,
+        size,
+        description,
+        FmtWriter::new(writer),
+    )
+}
+
+/// Encode data and fill it into an SVG template, replacing the element whose ID is `placeholder_id`. The QR code is scaled to fit the `x`, `y`, `width` and `height` attributes of the placeholder.
+#[inline]
+pub fn fill_svg_template<D: IntoQrPayload, T: AsRef<str>, ID: AsRef<str>>(
+    data: D,
+    ecc: QrCodeEcc,
+    template: T,
+    placeholder_id: ID,
+) -> Result<String, QRCodeError> {
+    svg_template::fill_svg_template_inner(
+        &generate_qrcode(data, ecc)?,
+        template.as_ref(),
+        placeholder_id.as_ref(),
+    )
+}
+
+/// Encode text and fill it into an SVG template, replacing the element whose ID is `placeholder_id`. The QR code is scaled to fit the `x`, `y`, `width` and `height` attributes of the placeholder.
+#[inline]
+pub fn fill_svg_template_from_str<S: AsRef<str>, T: AsRef<str>, ID: AsRef<str>>(
+    text: S,
+    ecc: QrCodeEcc,
+    template: T,
+    placeholder_id: ID,
+) -> Result<String, QRCodeError> {
+    svg_template::fill_svg_template_inner(
+        &generate_qrcode_from_str(text, ecc)?,
+        template.as_ref(),
+        placeholder_id.as_ref(),
+    )
+}
+
+/// Encode segments and fill them into an SVG template, replacing the element whose ID is `placeholder_id`. The QR code is scaled to fit the `x`, `y`, `width` and `height` attributes of the placeholder.
+#[inline]
+pub fn fill_svg_template_from_segments<T: AsRef<str>, ID: AsRef<str>>(
+    segments: &[QrSegment],
+    ecc: QrCodeEcc,
+    template: T,
+    placeholder_id: ID,
+) -> Result<String, QRCodeError> {
+    svg_template::fill_svg_template_inner(
+        &generate_qrcode_from_segments(segments, ecc)?,
+        template.as_ref(),
+        placeholder_id.as_ref(),
+    )
+}
@@ -0,0 +1,267 @@
+use std::io::Write;
+
+use qrcodegen::QrCode;
+
+use crate::{
+    escape, layout,
+    print_marks::{crop_mark_lines, registration_crosshair, registration_marks, MARK_STROKE_WIDTH},
+    svg_filter::SVG_FILTER_ID,
+    zone::to_zone_map,
+    Color, ModuleShape, QRCodeError, RenderOptions, Zone,
+};
+
+/// The length of the parts of a SVG image other than the path data of modules, such as the XML declaration, the background and the closing tag.
+const SVG_OVERHEAD: usize = 512;
+
+/// The number of decimal digits of `n`.
+#[inline]
+fn decimal_digits(n: usize) -> usize {
+    n.checked_ilog10().unwrap_or(0) as usize + 1
+}
+
+/// Estimate the length of a SVG image rendered by `to_svg_inner`, assuming about half of the modules are dark squares, so that buffers rarely need to grow and small QR codes do not over-allocate.
+pub(crate) fn estimate_svg_len(
+    qr: &QrCode,
+    size: usize,
+    description: Option<&str>,
+    options: &RenderOptions,
+) -> usize {
+    let modules = qr.size() as usize;
+
+    let point_size = size / (modules + 2 * options.quiet_zone).max(1);
+
+    // e.g. `M12 34h5v5H12V34`
+    let module_len =
+        6 + 4 * decimal_digits(size + 2 * options.slug()) + 2 * decimal_digits(point_size);
+
+    SVG_OVERHEAD + description.map_or(0, str::len) + modules * modules / 2 * module_len
+}
+
+/// Render a QR code to a SVG image. `description` overrides the description in `options`.
+pub(crate) fn to_svg_inner<W: Write>(
+    qr: &QrCode,
+    size: usize,
+    description: Option<&str>,
+    options: &RenderOptions,
+    mut writer: W,
+) -> Result<(), QRCodeError> {
+    let (point_size, margin) = layout(qr, size, options.quiet_zone)?;
+
+    let s = qr.size();
+
+    let bleed = options.bleed;
+    let slug = options.slug();
+
+    let page_size = size + 2 * slug;
+
+    let caption_height = if options.caption.is_some() { (size / 8).max(1) } else { 0 };
+
+    let page_height = page_size + caption_height;
+
+    writer.write_fmt(format_args!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg width=\"{page_size}\" height=\"{page_height}\" shape-rendering=\"crispEdges\" version=\"1.1\" xmlns=\"http://www.w3.org/2000/svg\">\n"))?;
+
+    match description {
+        Some(description) => {
+            if !description.is_empty() {
+                writer.write_all(b"\t<desc>")?;
+                escape::encode_safe_to_writer(description, &mut writer)?;
+                writer.write_all(b"</desc>\n")?;
+            }
+        },
+        None => {
+            writer.write_fmt(format_args!(
+                "\t<desc>{name} {version} by magiclen.org</desc>\n",
+                name = env!("CARGO_PKG_NAME"),
+                version = env!("CARGO_PKG_VERSION")
+            ))?;
+        },
+    }
+
+    let (quiet_zone_color, light_color, dark_color) = options.colors();
+
+    let background_fill = quiet_zone_color.to_svg_fill();
+
+    if slug == 0 {
+        writer.write_fmt(format_args!(
+            "\t<rect width=\"{size}\" height=\"{size}\" {background_fill}/>\n"
+        ))?;
+    } else {
+        let background_offset = slug - bleed;
+        let background_size = size + 2 * bleed;
+
+        writer.write_fmt(format_args!(
+            "\t<rect x=\"{background_offset}\" y=\"{background_offset}\" \
+             width=\"{background_size}\" height=\"{background_size}\" {background_fill}/>\n"
+        ))?;
+    }
+
+    if quiet_zone_color != light_color {
+        let data_offset = margin + slug;
+        let data_size = s as usize * point_size;
+
+        writer.write_fmt(format_args!(
+            "\t<rect x=\"{data_offset}\" y=\"{data_offset}\" width=\"{data_size}\" \
+             height=\"{data_size}\" {}/>\n",
+            light_color.to_svg_fill()
+        ))?;
+    }
+
+    if let Some(border) = options.border.as_ref() {
+        let (offset, outer_size) =
+            border.outer_bounds(margin + slug, s as usize * point_size, point_size);
+
+        // the stroke is centered on the outline of the rectangle
+        let half_width = border.width as f64 / 2.0;
+        let offset = offset + half_width;
+        let size = outer_size - border.width as f64;
+        let radius = (border.radius as f64 - half_width).max(0.0);
+
+        writer.write_fmt(format_args!(
+            "\t<rect x=\"{offset}\" y=\"{offset}\" width=\"{size}\" height=\"{size}\" \
+             rx=\"{radius}\" fill=\"none\" stroke=\"{}\"",
+            border.color.to_hex()
+        ))?;
+
+        if border.color.a < 255 {
+            writer.write_fmt(format_args!(" stroke-opacity=\"{}\"", border.color.opacity()))?;
+        }
+
+        writer.write_fmt(format_args!(" stroke-width=\"{}\"", border.width))?;
+
+        if radius > 0.0 {
+            writer.write_all(b" shape-rendering=\"geometricPrecision\"")?;
+        }
+
+        writer.write_all(b"/>\n")?;
+    }
+
+    if let Some(svg_filter) = options.svg_filter.as_ref() {
+        svg_filter.write_defs(&mut writer, page_size)?;
+    }
+
+    let zone_colors = options.zone_colors();
+
+    // modules are grouped into one path per distinct color, in the order of the zones
+    let mut path_colors: Vec<Color> = Vec::with_capacity(zone_colors.len());
+
+    for color in zone_colors {
+        if !path_colors.contains(&color) {
+            path_colors.push(color);
+        }
+    }
+
+    let shaped = options.shapes_data_modules();
+
+    let gap_inset = point_size as f64 * options.module_gap_fraction() / 2.0;
+
+    let zones = if path_colors.len() > 1 || shaped { Some(to_zone_map(qr)) } else { None };
+
+    for path_color in path_colors {
+        writer.write_all(b"\t<path ")?;
+
+        if path_color != Color::BLACK {
+            writer.write_fmt(format_args!("{} ", path_color.to_svg_fill()))?;
+        }
+
+        if options.svg_filter.is_some() {
+            writer.write_fmt(format_args!("filter=\"url(#{SVG_FILTER_ID})\" "))?;
+        }
+
+        if shaped {
+            writer.write_all(b"shape-rendering=\"geometricPrecision\" ")?;
+        }
+
+        writer.write_all(b"d=\"")?;
+
+        for i in 0..s {
+            for j in 0..s {
+                if !qr.get_module(j, i) {
+                    continue;
+                }
+
+                let (shape, inset) = match zones.as_ref() {
+                    Some(zones) => {
+                        let zone = zones[(i * s + j) as usize];
+
+                        if zone_colors[zone as usize] != path_color {
+                            continue;
+                        }
+
+                        if zone == Zone::Data {
+                            (options.module_shape, gap_inset)
+                        } else {
+                            (ModuleShape::Square, 0.0)
+                        }
+                    },
+                    None => (ModuleShape::Square, 0.0),
+                };
+
+                let x = (j as usize * point_size + margin + slug) as f64 + inset;
+                let y = (i as usize * point_size + margin + slug) as f64 + inset;
+
+                shape.write_svg_path(&mut writer, x, y, point_size as f64 - 2.0 * inset)?;
+            }
+        }
+
+        writer.write_all(b"\"/>\n")?;
+    }
+
+    if options.crop_marks {
+        let offset = slug as f64;
+
+        writer.write_fmt(format_args!(
+            "\t<g fill=\"none\" stroke=\"#000\" stroke-width=\"{MARK_STROKE_WIDTH}\" \
+             shape-rendering=\"geometricPrecision\">\n\t\t<path d=\""
+        ))?;
+
+        let (centers, radius) = registration_marks(size, bleed);
+
+        let lines = crop_mark_lines(size, bleed)
+            .into_iter()
+            .chain(centers.into_iter().flat_map(|center| registration_crosshair(center, radius)));
+
+        for (x1, y1, x2, y2) in lines {
+            writer.write_fmt(format_args!(
+                "M{} {}L{} {}",
+                x1 + offset,
+                y1 + offset,
+                x2 + offset,
+                y2 + offset
+            ))?;
+        }
+
+        writer.write_all(b"\"/>\n")?;
+
+        for (cx, cy) in centers {
+            writer.write_fmt(format_args!(
+                "\t\t<circle cx=\"{}\" cy=\"{}\" r=\"{radius}\"/>\n",
+                cx + offset,
+                cy + offset
+            ))?;
+        }
+
+        writer.write_all(b"\t</g>\n")?;
+    }
+
+    if let Some(caption) = options.caption.as_deref() {
+        let center = page_size as f64 / 2.0;
+        let baseline = page_size as f64 + caption_height as f64 / 2.0;
+        let font_size = (caption_height as f64 * 0.6).round().max(1.0);
+
+        writer.write_fmt(format_args!(
+            "\t<rect y=\"{page_size}\" width=\"{page_size}\" height=\"{caption_height}\" \
+             {background_fill}/>\n\t<text x=\"{center}\" y=\"{baseline}\" \
+             font-family=\"sans-serif\" font-size=\"{font_size}\" text-anchor=\"middle\" \
+             dominant-baseline=\"central\" {}>",
+            dark_color.to_svg_fill()
+        ))?;
+        escape::encode_text_to_writer(caption, &mut writer)?;
+        writer.write_all(b"</text>\n")?;
+    }
+
+    writer.write_all(b"</svg>")?;
+
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use std::fmt::Write;
+
+use qrcodegen::QrCode;
+
+use crate::{escape, QRCodeError};
+
+/// Find the end of the tag starting at `start`, skipping `>` in quoted attribute values, and return the index after the `>`.
+fn find_tag_end(template: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+
+    for (i, c) in template[start..].char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(start + i + 1),
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// Find the end of the element whose start tag named `name` ends at `tag_end`, counting nested elements with the same name, and return the index after its closing tag.
+fn find_element_end(template: &str, name: &str, tag_end: usize) -> Option<usize> {
+    let is_name_end =
+        |rest: &str| rest.starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/');
+
+    let mut depth = 1usize;
+    let mut i = tag_end;
+
+    while let Some(offset) = template[i..].find('<') {
+        let start = i + offset;
+        let rest = &template[(start + 1)..];
+
+        // comments and character data can contain anything but their terminators
+        if rest.starts_with("!--") {
+            i = start + rest.find("-->")? + 4;
+            continue;
+        }
+
+        if rest.starts_with("![CDATA[") {
+            i = start + rest.find("]]>")? + 4;
+            continue;
+        }
+
+        let end = find_tag_end(template, start)?;
+
+        if let Some(rest) = rest.strip_prefix('/') {
+            if rest.starts_with(name) && is_name_end(&rest[name.len()..]) {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(end);
+                }
+            }
+        } else if rest.starts_with(name)
+            && is_name_end(&rest[name.len()..])
+            && !template[..end].ends_with("/>")
+        {
+            depth += 1;
+        }
+
+        i = end;
+    }
+
+    None
+}
+
+/// Find the start tag containing `id="<id>"` and return its byte range and the byte range of the whole element.
+fn find_element(template: &str, id: &str) -> Option<((usize, usize), (usize, usize))> {
+    let mut escaped_id = String::with_capacity(id.len());
+
+    escape::encode_double_quoted_attribute_to_string(id, &mut escaped_id);
+
+    let id_index = [format!("id=\"{escaped_id}\""), format!("id='{escaped_id}'")]
+        .iter()
+        .filter_map(|pattern| {
+            template.match_indices(pattern.as_str()).map(|(i, _)| i).find(|&i| {
+                // make sure it is not a part of another attribute name, e.g. `data-id`
+                template[..i].ends_with(|c: char| c.is_ascii_whitespace())
+            })
+        })
+        .min()?;
+
+    let start = template[..id_index].rfind('<')?;
+    let tag_end = find_tag_end(template, id_index)?;
+
+    let end = if template[..tag_end].ends_with("/>") {
+        tag_end
+    } else {
+        let name_len = template[(start + 1)..]
+            .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')?;
+
+        find_element_end(template, &template[(start + 1)..(start + 1 + name_len)], tag_end)?
+    };
+
+    Some(((start, tag_end), (start, end)))
+}
+
+/// Read a numeric attribute in pixels from a start tag.
+fn read_length(tag: &str, name: &str) -> Option<Result<f64, QRCodeError>> {
+    let index = [format!(" {name}=\""), format!(" {name}='")]
+        .iter()
+        .filter_map(|pattern| tag.find(pattern.as_str()).map(|i| i + pattern.len()))
+        .min()?;
+
+    let quote = tag.as_bytes()[index - 1] as char;
+
+    let value = &tag[index..(index + tag[index..].find(quote)?)];
+
+    let value = value.trim();
+    let value = value.strip_suffix("px").unwrap_or(value);
+
+    Some(value.parse::<f64>().ok().filter(|v| v.is_finite()).ok_or(QRCodeError::InvalidPlaceholder))
+}
+
+/// Replace the placeholder element with the QR code scaled to fit its `x`, `y`, `width` and `height` attributes and centered. The ID is kept on the group of the QR code.
+pub(crate) fn fill_svg_template_inner(
+    qr: &QrCode,
+    template: &str,
+    placeholder_id: &str,
+) -> Result<String, QRCodeError> {
+    let ((tag_start, tag_end), (element_start, element_end)) =
+        find_element(template, placeholder_id).ok_or(QRCodeError::PlaceholderNotFound)?;
+
+    let tag = &template[tag_start..tag_end];
+
+    let x = read_length(tag, "x").transpose()?.unwrap_or(0.0);
+    let y = read_length(tag, "y").transpose()?.unwrap_or(0.0);
+    let width = read_length(tag, "width").ok_or(QRCodeError::InvalidPlaceholder)??;
+    let height = read_length(tag, "height").ok_or(QRCodeError::InvalidPlaceholder)??;
+
+    if width <= 0.0 || height <= 0.0 {
+        return Err(QRCodeError::InvalidPlaceholder);
+    }
+
+    let s = qr.size();
+
+    // one module of margin on each side, the same proportion as the other SVG functions
+    let units = s + 2;
+
+    let size = width.min(height);
+    let scale = size / f64::from(units);
+
+    let tx = x + (width - size) / 2.0;
+    let ty = y + (height - size) / 2.0;
+
+    let mut group = String::with_capacity(4096);
+
+    group.push_str("<g id=\"");
+    escape::encode_double_quoted_attribute_to_string(placeholder_id, &mut group);
+
+    let _ = group.write_fmt(format_args!(
+        "\" transform=\"translate({tx} {ty}) scale({scale})\" \
+         shape-rendering=\"crispEdges\"><rect width=\"{units}\" height=\"{units}\" \
+         fill=\"#FFF\"/><path d=\""
+    ));
+
+    for i in 0..s {
+        for j in 0..s {
+            if qr.get_module(j, i) {
+                let _ = group.write_fmt(format_args!("M{} {}h1v1h-1z", j + 1, i + 1));
+            }
+        }
+    }
+
+    group.push_str("\"/></g>");
+
+    let mut result = String::with_capacity(template.len() + group.len());
+
+    result.push_str(&template[..element_start]);
+    result.push_str(&group);
+    result.push_str(&template[element_end..]);
+
+    Ok(result)
+}
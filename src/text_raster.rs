@@ -0,0 +1,122 @@
+use ab_glyph::{point, Font as _, GlyphId, ScaleFont};
+
+use crate::{raster::blend, Color, Font};
+
+/// Place the glyphs of a line of text, returning each glyph with its position relative to the left end of the baseline.
+#[cfg(not(feature = "shaping"))]
+#[inline]
+fn layout_glyphs(font: &Font, font_size: f32, text: &str) -> Vec<(GlyphId, f32, f32)> {
+    layout_unshaped_glyphs(font, font_size, text)
+}
+
+/// Place the glyphs of a line of text one character after another from left to right, with kerning but without shaping.
+fn layout_unshaped_glyphs(font: &Font, font_size: f32, text: &str) -> Vec<(GlyphId, f32, f32)> {
+    let scaled_font = font.font.as_scaled(font_size);
+
+    let mut glyphs = Vec::with_capacity(text.len());
+
+    let mut caret = 0.0;
+    let mut previous = None;
+
+    for c in text.chars() {
+        let glyph_id = scaled_font.glyph_id(c);
+
+        if let Some(previous) = previous {
+            caret += scaled_font.kern(previous, glyph_id);
+        }
+
+        glyphs.push((glyph_id, caret, 0.0));
+
+        caret += scaled_font.h_advance(glyph_id);
+        previous = Some(glyph_id);
+    }
+
+    glyphs
+}
+
+/// Place the glyphs of a line of text, returning each glyph with its position relative to the left end of the baseline. The text is split into runs of the same direction in visual order, and each run is shaped, so that right-to-left and complex scripts are joined and ordered correctly.
+#[cfg(feature = "shaping")]
+fn layout_glyphs(font: &Font, font_size: f32, text: &str) -> Vec<(GlyphId, f32, f32)> {
+    use rustybuzz::{Direction, UnicodeBuffer};
+    use unicode_bidi::BidiInfo;
+
+    let face = match rustybuzz::Face::from_slice(font.font.as_slice(), 0) {
+        Some(face) => face,
+        // `ab_glyph` accepts some fonts which `rustybuzz` cannot read, whose text is still drawn without shaping
+        None => return layout_unshaped_glyphs(font, font_size, text),
+    };
+
+    let scaled_font = font.font.as_scaled(font_size);
+
+    let h_scale = scaled_font.h_scale_factor();
+    let v_scale = scaled_font.v_scale_factor();
+
+    let mut glyphs = Vec::with_capacity(text.len());
+
+    let mut caret = 0.0;
+
+    let bidi_info = BidiInfo::new(text, None);
+
+    for paragraph in bidi_info.paragraphs.iter() {
+        let (levels, runs) = bidi_info.visual_runs(paragraph, paragraph.range.clone());
+
+        for run in runs {
+            let mut buffer = UnicodeBuffer::new();
+
+            buffer.push_str(&text[run.clone()]);
+            buffer.guess_segment_properties();
+            buffer.set_direction(if levels[run.start].is_rtl() {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            });
+
+            let output = rustybuzz::shape(&face, &[], buffer);
+
+            for (info, position) in output.glyph_infos().iter().zip(output.glyph_positions()) {
+                glyphs.push((
+                    GlyphId(info.glyph_id as u16),
+                    caret + position.x_offset as f32 * h_scale,
+                    -(position.y_offset as f32) * v_scale,
+                ));
+
+                caret += position.x_advance as f32 * h_scale;
+            }
+        }
+    }
+
+    glyphs
+}
+
+/// Draw a line of text on RGBA8 pixels. `(x, y)` is the left end of the baseline and `font_size` is the height of the font in pixels.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw_text(
+    rgba: &mut [u8],
+    width: usize,
+    height: usize,
+    font: &Font,
+    font_size: f32,
+    x: f32,
+    y: f32,
+    text: &str,
+    color: Color,
+) {
+    for (glyph_id, dx, dy) in layout_glyphs(font, font_size, text) {
+        let glyph = glyph_id.with_scale_and_position(font_size, point(x + dx, y + dy));
+
+        if let Some(outlined) = font.font.outline_glyph(glyph) {
+            let bounds = outlined.px_bounds();
+
+            outlined.draw(|gx, gy, coverage| {
+                let px = bounds.min.x as i64 + i64::from(gx);
+                let py = bounds.min.y as i64 + i64::from(gy);
+
+                if px >= 0 && py >= 0 && (px as usize) < width && (py as usize) < height {
+                    let i = (py as usize * width + px as usize) * 4;
+
+                    blend(&mut rgba[i..(i + 4)], color, f64::from(coverage));
+                }
+            });
+        }
+    }
+}
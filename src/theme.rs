@@ -0,0 +1,12 @@
+/// Ready-made styles for `RenderOptions::theme`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Theme {
+    /// Square modules without decorations.
+    Classic,
+    /// Rounded modules.
+    Rounded,
+    /// Round dots as modules.
+    Dots,
+    /// Rounded modules in a rounded frame outside a quiet zone of at least 4 modules, with a caption under SVG images. The quiet zone is widened by 2 modules to make room for the frame. Set the caption to `None` to render badges to other formats.
+    Badge(String),
+}
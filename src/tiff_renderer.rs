@@ -0,0 +1,84 @@
+use std::{
+    borrow::Cow,
+    io::{Cursor, Write},
+};
+
+use qrcodegen::QrCode;
+use tiff::{
+    encoder::{colortype::CMYK8, TiffEncoder, TiffValue},
+    tags::{Tag, Type},
+};
+
+use crate::{
+    raster::{to_region_map, DARK, LIGHT, QUIET_ZONE, REGIONS},
+    QRCodeError, RenderOptions,
+};
+
+/// The bytes of buffers of a pixel, four bytes of CMYK8 pixels and four bytes of the encoded image. The region map is freed while the encoded image is being written.
+const BYTES_PER_PIXEL: u64 = 8;
+
+/// Opaque bytes, which is the field type of the ICC profile tag.
+struct Undefined<'a>(&'a [u8]);
+
+impl<'a> TiffValue for Undefined<'a> {
+    const BYTE_LEN: u8 = 1;
+    const FIELD_TYPE: Type = Type::UNDEFINED;
+
+    #[inline]
+    fn count(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn data(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.0)
+    }
+}
+
+/// Render a QR code to a CMYK TIFF image painted with the CMYK inks in the options.
+pub(crate) fn to_tiff_inner<W: Write>(
+    qr: &QrCode,
+    size: usize,
+    options: &RenderOptions,
+    mut writer: W,
+) -> Result<(), QRCodeError> {
+    if let Some(icc_profile) = options.icc_profile.as_ref() {
+        icc_profile.check_color_space(&[b"CMYK"], "CMYK TIFF")?;
+    }
+
+    let regions = to_region_map(qr, size, options, BYTES_PER_PIXEL)?;
+
+    let (quiet_zone, light, dark) = options.inks();
+
+    let mut palette = [[0u8; 4]; REGIONS];
+
+    palette[QUIET_ZONE as usize] = quiet_zone.to_u8_samples();
+    palette[LIGHT as usize] = light.to_u8_samples();
+    palette[DARK as usize..].fill(dark.to_u8_samples());
+
+    let mut cmyk = Vec::with_capacity(regions.len() * 4);
+
+    for region in regions {
+        cmyk.extend_from_slice(&palette[region as usize]);
+    }
+
+    let mut tiff = Cursor::new(Vec::with_capacity(cmyk.len() + 1024));
+
+    {
+        let mut encoder = TiffEncoder::new(&mut tiff)?;
+
+        let mut image = encoder.new_image::<CMYK8>(size as u32, size as u32)?;
+
+        if let Some(icc_profile) = options.icc_profile.as_ref() {
+            image.encoder().write_tag(Tag::IccProfile, Undefined(icc_profile.as_bytes()))?;
+        }
+
+        image.write_data(&cmyk)?;
+    }
+
+    writer.write_all(tiff.get_ref())?;
+
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use std::io::{self, Write};
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+use qrcodegen::{DataTooLong, QrCode, QrCodeEcc};
+
+use crate::{OutputSpec, QRCodeError};
+
+/// Outputs of renderings whose lengths can be recorded.
+pub(crate) trait RenderOutput {
+    /// The length in bytes.
+    #[cfg(feature = "tracing")]
+    fn output_len(&self) -> usize;
+}
+
+impl RenderOutput for Vec<u8> {
+    #[cfg(feature = "tracing")]
+    #[inline]
+    fn output_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// The number of bytes written to a writer.
+impl RenderOutput for usize {
+    #[cfg(feature = "tracing")]
+    #[inline]
+    fn output_len(&self) -> usize {
+        *self
+    }
+}
+
+/// A writer which counts the bytes written through it.
+#[derive(Debug)]
+pub(crate) struct CountingWriter<W> {
+    inner: W,
+    count: usize,
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        let n = self.inner.write(buf)?;
+
+        self.count += n;
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.inner.flush()
+    }
+}
+
+/// Run an encoding of a payload with `payload_len` characters or bytes. With the `tracing` feature, it is wrapped in an `encode` span whose `version`, `modules`, `duration_us` and `error` fields are recorded when it finishes.
+#[inline]
+pub(crate) fn encode<F: FnOnce() -> Result<QrCode, DataTooLong>>(
+    payload_len: usize,
+    ecc: QrCodeEcc,
+    f: F,
+) -> Result<QrCode, QRCodeError> {
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "encode",
+        payload_len,
+        ecc = ?ecc,
+        version = tracing::field::Empty,
+        modules = tracing::field::Empty,
+        duration_us = tracing::field::Empty,
+        error = tracing::field::Empty,
+    );
+    #[cfg(feature = "tracing")]
+    let _entered = span.enter();
+    #[cfg(feature = "tracing")]
+    let start = Instant::now();
+    #[cfg(not(feature = "tracing"))]
+    let _ = (payload_len, ecc);
+
+    let qr = f().map_err(|_| QRCodeError::DataTooLong);
+
+    #[cfg(feature = "tracing")]
+    {
+        span.record("duration_us", start.elapsed().as_micros() as u64);
+
+        match qr.as_ref() {
+            Ok(qr) => {
+                span.record("version", qr.version().value());
+                span.record("modules", qr.size());
+            },
+            Err(err) => {
+                span.record("error", tracing::field::display(err));
+            },
+        }
+    }
+
+    qr
+}
+
+/// Run a rendering. With the `tracing` feature, it is wrapped in a `render` span whose `output_len`, `duration_us` and `error` fields are recorded when it finishes.
+#[inline]
+pub(crate) fn render<T: RenderOutput, F: FnOnce() -> Result<T, QRCodeError>>(
+    spec: OutputSpec,
+    f: F,
+) -> Result<T, QRCodeError> {
+    #[cfg(feature = "tracing")]
+    let span = tracing::debug_span!(
+        "render",
+        format = ?spec.format,
+        size = spec.size,
+        output_len = tracing::field::Empty,
+        duration_us = tracing::field::Empty,
+        error = tracing::field::Empty,
+    );
+    #[cfg(feature = "tracing")]
+    let _entered = span.enter();
+    #[cfg(feature = "tracing")]
+    let start = Instant::now();
+    #[cfg(not(feature = "tracing"))]
+    let _ = spec;
+
+    let output = f();
+
+    #[cfg(feature = "tracing")]
+    {
+        span.record("duration_us", start.elapsed().as_micros() as u64);
+
+        match output.as_ref() {
+            Ok(output) => {
+                span.record("output_len", output.output_len());
+            },
+            Err(err) => {
+                span.record("error", tracing::field::display(err));
+            },
+        }
+    }
+
+    output
+}
+
+/// Run a rendering to a writer, like `render`, counting the bytes written for the `output_len` field.
+#[inline]
+pub(crate) fn render_to_writer<
+    W: Write,
+    F: FnOnce(&mut CountingWriter<W>) -> Result<(), QRCodeError>,
+>(
+    spec: OutputSpec,
+    writer: W,
+    f: F,
+) -> Result<(), QRCodeError> {
+    let mut writer = CountingWriter {
+        inner: writer, count: 0
+    };
+
+    render(spec, || {
+        f(&mut writer)?;
+
+        Ok(writer.count)
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use crate::{
+    bytewords::encode_minimal, crc32::crc32, fountain::choose_fragments, generate_qrcode_from_str,
+    to_matrix_inner, QRCodeError, QrCodeEcc, UrEncoderError,
+};
+
+/// An encoder of Blockchain Commons Uniform Resources (BC-UR). Messages longer than the maximum fragment length are split into fountain-coded parts, so that they can be transferred by an endless cycle of animated QR codes and decoded from any sufficient subset of the frames.
+#[derive(Debug, Clone)]
+pub struct UrEncoder {
+    ur_type:   String,
+    message:   Vec<u8>,
+    checksum:  u32,
+    fragments: Vec<Vec<u8>>,
+    seq_num:   u32,
+}
+
+impl UrEncoder {
+    /// Create an encoder of a CBOR-encoded message with the UR type, e.g. `"crypto-psbt"`.
+    pub fn new<S: Into<String>, M: Into<Vec<u8>>>(
+        ur_type: S,
+        cbor: M,
+        max_fragment_len: usize,
+    ) -> Result<Self, UrEncoderError> {
+        let ur_type = ur_type.into();
+        let message = cbor.into();
+
+        if ur_type.is_empty()
+            || !ur_type.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        {
+            return Err(UrEncoderError::InvalidType);
+        }
+
+        if message.is_empty() {
+            return Err(UrEncoderError::EmptyMessage);
+        }
+
+        if max_fragment_len == 0 {
+            return Err(UrEncoderError::InvalidFragmentLength);
+        }
+
+        let checksum = crc32(&message);
+
+        // use the smallest number of equally long fragments
+        let fragment_count = (message.len() + max_fragment_len - 1) / max_fragment_len;
+        let fragment_len = (message.len() + fragment_count - 1) / fragment_count;
+
+        let fragments = message
+            .chunks(fragment_len)
+            .map(|chunk| {
+                let mut fragment = chunk.to_vec();
+
+                fragment.resize(fragment_len, 0);
+
+                fragment
+            })
+            .collect();
+
+        Ok(UrEncoder {
+            ur_type,
+            message,
+            checksum,
+            fragments,
+            seq_num: 0,
+        })
+    }
+
+    /// Create an encoder of arbitrary bytes with the `bytes` UR type.
+    #[inline]
+    pub fn bytes<D: AsRef<[u8]>>(data: D, max_fragment_len: usize) -> Result<Self, UrEncoderError> {
+        let data = data.as_ref();
+
+        let mut cbor = Vec::with_capacity(data.len() + 9);
+
+        write_cbor_head(&mut cbor, 2, data.len() as u64);
+        cbor.extend_from_slice(data);
+
+        Self::new("bytes", cbor, max_fragment_len)
+    }
+
+    /// Whether the whole message fits in one part.
+    #[inline]
+    pub fn is_single_part(&self) -> bool {
+        self.fragments.len() == 1
+    }
+
+    /// The number of pure fragments. Any `seq_len` distinct parts are usually enough to decode the message.
+    #[inline]
+    pub fn seq_len(&self) -> usize {
+        self.fragments.len()
+    }
+
+    /// Produce the next part as a lowercase UR string, e.g. `ur:bytes/1-9/lpad...`. A single-part message is always produced as a whole, e.g. `ur:bytes/hdeh...`.
+    pub fn next_part(&mut self) -> String {
+        // the sequence number wraps at 2^32 and skips 0
+        self.seq_num = self.seq_num.checked_add(1).unwrap_or(1);
+
+        if self.is_single_part() {
+            return format!("ur:{}/{}", self.ur_type, encode_minimal(&self.message));
+        }
+
+        let seq_len = self.seq_len();
+
+        let mut mixed = vec![0u8; self.fragments[0].len()];
+
+        for index in choose_fragments(self.seq_num, seq_len, self.checksum) {
+            for (m, f) in mixed.iter_mut().zip(self.fragments[index].iter()) {
+                *m ^= f;
+            }
+        }
+
+        let mut cbor = Vec::with_capacity(mixed.len() + 24);
+
+        write_cbor_head(&mut cbor, 4, 5);
+        write_cbor_head(&mut cbor, 0, u64::from(self.seq_num));
+        write_cbor_head(&mut cbor, 0, seq_len as u64);
+        write_cbor_head(&mut cbor, 0, self.message.len() as u64);
+        write_cbor_head(&mut cbor, 0, u64::from(self.checksum));
+        write_cbor_head(&mut cbor, 2, mixed.len() as u64);
+        cbor.extend_from_slice(&mixed);
+
+        format!("ur:{}/{}-{seq_len}/{}", self.ur_type, self.seq_num, encode_minimal(&cbor))
+    }
+
+    /// Turn the encoder into an endless iterator of QR code matrices. Parts are uppercased so that they are encoded in the compact alphanumeric mode.
+    #[inline]
+    pub fn frames(self, ecc: QrCodeEcc) -> UrFrames {
+        UrFrames {
+            encoder: self,
+            ecc,
+        }
+    }
+}
+
+impl Iterator for UrEncoder {
+    type Item = String;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_part())
+    }
+}
+
+/// An endless iterator of QR code matrices of BC-UR parts, created by `UrEncoder::frames`.
+#[derive(Debug, Clone)]
+pub struct UrFrames {
+    encoder: UrEncoder,
+    ecc:     QrCodeEcc,
+}
+
+impl Iterator for UrFrames {
+    type Item = Result<Vec<Vec<bool>>, QRCodeError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let part = self.encoder.next_part().to_ascii_uppercase();
+
+        Some(generate_qrcode_from_str(part, self.ecc).map(to_matrix_inner))
+    }
+}
+
+/// Write the head of a CBOR data item with the shortest encoding of the argument.
+fn write_cbor_head(cbor: &mut Vec<u8>, major_type: u8, n: u64) {
+    let major_type = major_type << 5;
+
+    if n < 24 {
+        cbor.push(major_type | n as u8);
+    } else if n <= u64::from(u8::MAX) {
+        cbor.push(major_type | 24);
+        cbor.push(n as u8);
+    } else if n <= u64::from(u16::MAX) {
+        cbor.push(major_type | 25);
+        cbor.extend_from_slice(&(n as u16).to_be_bytes());
+    } else if n <= u64::from(u32::MAX) {
+        cbor.push(major_type | 26);
+        cbor.extend_from_slice(&(n as u32).to_be_bytes());
+    } else {
+        cbor.push(major_type | 27);
+        cbor.extend_from_slice(&n.to_be_bytes());
+    }
+}
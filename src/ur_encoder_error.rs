@@ -0,0 +1,29 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+/// Errors when creating a `UrEncoder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrEncoderError {
+    InvalidType,
+    EmptyMessage,
+    InvalidFragmentLength,
+}
+
+impl Display for UrEncoderError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            UrEncoderError::InvalidType => {
+                f.write_str("the UR type should only contain lowercase letters, digits and hyphens")
+            },
+            UrEncoderError::EmptyMessage => f.write_str("the message is empty"),
+            UrEncoderError::InvalidFragmentLength => {
+                f.write_str("the maximum fragment length should not be 0")
+            },
+        }
+    }
+}
+
+impl Error for UrEncoderError {}
@@ -0,0 +1,6 @@
+use crate::QRCodeError;
+
+/// Check settings before rendering, so that malformed input fails early with `QRCodeError::InvalidInput` instead of producing unscannable codes.
+pub trait Validate {
+    fn validate(&self) -> Result<(), QRCodeError>;
+}
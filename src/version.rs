@@ -0,0 +1,113 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+};
+
+use crate::QRCodeError;
+
+/// A QR code version from `1` to `40`, which decides the number of modules on each side. It can be converted to/from `qrcodegen::Version`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version(u8);
+
+impl Version {
+    /// The largest version, `40`.
+    pub const MAX: Version = Version(40);
+    /// The smallest version, `1`.
+    pub const MIN: Version = Version(1);
+
+    /// Create a version. `None` means it is out of the range.
+    #[inline]
+    pub const fn new(version: u8) -> Option<Self> {
+        if version >= Self::MIN.0 && version <= Self::MAX.0 {
+            Some(Version(version))
+        } else {
+            None
+        }
+    }
+
+    /// Create a version, clamping the value to the range.
+    #[inline]
+    pub const fn clamped(version: u8) -> Self {
+        if version < Self::MIN.0 {
+            Self::MIN
+        } else if version > Self::MAX.0 {
+            Self::MAX
+        } else {
+            Version(version)
+        }
+    }
+
+    /// Get the version of a QR code which has `modules` modules on each side.
+    #[inline]
+    pub const fn from_modules(modules: usize) -> Option<Self> {
+        if modules < 17 || (modules - 17) % 4 != 0 || (modules - 17) / 4 > Self::MAX.0 as usize {
+            return None;
+        }
+
+        Self::new(((modules - 17) / 4) as u8)
+    }
+
+    /// Get the value from `1` to `40`.
+    #[inline]
+    pub const fn value(self) -> u8 {
+        self.0
+    }
+
+    /// Get the number of modules on each side, i.e. `version * 4 + 17`.
+    #[inline]
+    pub const fn modules(self) -> usize {
+        self.0 as usize * 4 + 17
+    }
+
+    /// Iterate over the versions in `range`, clamped to `1` to `40`.
+    #[inline]
+    pub fn range(range: RangeInclusive<u8>) -> impl DoubleEndedIterator<Item = Self> {
+        let (start, end) = (Self::clamped(*range.start()).0, Self::clamped(*range.end()).0);
+
+        (start..=end).map(Version)
+    }
+
+    /// Iterate over every version from `1` to `40`.
+    #[inline]
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> {
+        Self::range(Self::MIN.0..=Self::MAX.0)
+    }
+}
+
+impl Display for Version {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl TryFrom<u8> for Version {
+    type Error = QRCodeError;
+
+    #[inline]
+    fn try_from(version: u8) -> Result<Self, Self::Error> {
+        Version::new(version)
+            .ok_or_else(|| QRCodeError::invalid_input("version", "must be between 1 and 40"))
+    }
+}
+
+impl From<Version> for u8 {
+    #[inline]
+    fn from(version: Version) -> Self {
+        version.0
+    }
+}
+
+impl From<Version> for qrcodegen::Version {
+    #[inline]
+    fn from(version: Version) -> Self {
+        qrcodegen::Version::new(version.0)
+    }
+}
+
+impl From<qrcodegen::Version> for Version {
+    #[inline]
+    fn from(version: qrcodegen::Version) -> Self {
+        Version(version.value())
+    }
+}
@@ -0,0 +1,80 @@
+use qrcodegen::QrCode;
+
+/// Structural zones of the modules of a QR code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Zone {
+    /// Modules which are not in the other zones, including the format and version information.
+    Data,
+    /// The three 7 × 7 finder patterns at the corners.
+    Finder,
+    /// The two lines of alternating modules between the finder patterns.
+    Timing,
+    /// The 5 × 5 alignment patterns of version 2 and above.
+    Alignment,
+}
+
+/// The center coordinates along each axis of the alignment patterns.
+pub(crate) fn alignment_pattern_positions(qr: &QrCode) -> Vec<i32> {
+    let version = qr.version().value();
+
+    if version == 1 {
+        return Vec::new();
+    }
+
+    let count = i32::from(version) / 7 + 2;
+
+    let step = if version == 32 {
+        26
+    } else {
+        (i32::from(version) * 4 + count * 2 + 1) / (count * 2 - 2) * 2
+    };
+
+    let mut positions: Vec<i32> = (0..(count - 1)).map(|i| qr.size() - 7 - i * step).collect();
+
+    positions.push(6);
+    positions.reverse();
+
+    positions
+}
+
+/// Classify every module of a QR code into zones, row by row.
+pub(crate) fn to_zone_map(qr: &QrCode) -> Vec<Zone> {
+    let s = qr.size();
+
+    let mut zones = vec![Zone::Data; (s * s) as usize];
+
+    let mut set = |x: i32, y: i32, zone: Zone| zones[(y * s + x) as usize] = zone;
+
+    for i in 8..(s - 8) {
+        set(i, 6, Zone::Timing);
+        set(6, i, Zone::Timing);
+    }
+
+    for (x, y) in [(0, 0), (s - 7, 0), (0, s - 7)] {
+        for dy in 0..7 {
+            for dx in 0..7 {
+                set(x + dx, y + dy, Zone::Finder);
+            }
+        }
+    }
+
+    let positions = alignment_pattern_positions(qr);
+    let last = positions.len().saturating_sub(1);
+
+    for (i, &x) in positions.iter().enumerate() {
+        for (j, &y) in positions.iter().enumerate() {
+            // the three corners are occupied by the finder patterns
+            if (i == 0 && (j == 0 || j == last)) || (i == last && j == 0) {
+                continue;
+            }
+
+            for dy in -2..=2 {
+                for dx in -2..=2 {
+                    set(x + dx, y + dy, Zone::Alignment);
+                }
+            }
+        }
+    }
+
+    zones
+}